@@ -67,18 +67,15 @@ pub trait FormatAssertionBuilder<T> {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 pub enum Exception<T> {
-    Specific { value: T },
+    Specific {
+        value: T,
+    },
+    #[default]
     Unspecified,
 }
 
-impl<T> Default for Exception<T> {
-    fn default() -> Self {
-        Exception::Unspecified
-    }
-}
-
 #[derive(Clone, Debug, PartialEq, Deserialize)]
 pub struct FormatCoverage<T> {
     pub format: String,
@@ -0,0 +1,10 @@
+#![allow(clippy::uninlined_format_args)]
+#![deny(
+    missing_copy_implementations,
+    trivial_casts,
+    trivial_numeric_casts,
+    unsafe_code,
+    unused_import_braces,
+    unused_qualifications,
+    missing_docs
+)]
@@ -0,0 +1,242 @@
+//! Detects which of [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601),
+//! [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) and
+//! [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557) a datetime is written in, for input from
+//! sources that don't say.
+//!
+//! The input is parsed with the `datetime` parser of each of the format crates, and the value is
+//! taken from the most specific grammar that accepts it: RFC 9557, which keeps the time zone and
+//! calendar suffixes, then RFC 3339, then ISO 8601. Like those parsers, detection doesn't check
+//! that the date exists in the calendar, see [`Date::validate`](winnow_datetime::Date::validate).
+//!
+//! # Example
+//!
+//! ```rust
+//! use winnow_datetime::writer::Notation;
+//! use winnow_datetime_detect::{detect, DateForm};
+//!
+//! let detected = detect("2015-06-26T16:43:23+02:00").unwrap();
+//! assert!(detected.standards.rfc3339 && detected.standards.rfc9557);
+//!
+//! let detected = detect("2015W261T164323Z").unwrap();
+//! assert!(!detected.standards.rfc3339);
+//!
+//! let iso8601 = detected.iso8601.unwrap();
+//! assert_eq!(iso8601.date, DateForm::Week);
+//! assert_eq!(iso8601.date_notation, Notation::Basic);
+//! ```
+
+mod clippy;
+
+use winnow_datetime::writer::Notation;
+use winnow_datetime::{Date, DateTime};
+
+/// The standards an input conforms to
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct Standards {
+    /// accepted by [`winnow_iso8601::parse_datetime`]
+    pub iso8601: bool,
+    /// accepted by [`winnow_rfc3339::parse_datetime`]
+    pub rfc3339: bool,
+    /// accepted by [`winnow_rfc9557::parse_datetime`]
+    pub rfc9557: bool,
+}
+
+/// How the date of an ISO 8601 datetime is written
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum DateForm {
+    /// year, month and day, `2015-06-26`
+    Calendar,
+    /// ISO week-numbering year, week and day of week, `2015-W26-5`
+    Week,
+    /// year and day of the year, `2015-177`
+    Ordinal,
+}
+
+/// The ISO 8601 notation an input is written in
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct IsoNotation {
+    /// the form of the date
+    pub date: DateForm,
+    /// whether the date has separators, `2015-06-26` or `20150626`
+    pub date_notation: Notation,
+    /// whether the time has separators, `16:43:23` or `164323`, an hour alone being basic
+    pub time_notation: Notation,
+}
+
+/// A datetime along with the standards it is written in
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Detected {
+    /// the parsed datetime
+    pub datetime: DateTime,
+    /// the standards the input conforms to
+    pub standards: Standards,
+    /// the ISO 8601 notation of the input, when it conforms to ISO 8601
+    pub iso8601: Option<IsoNotation>,
+}
+
+// the notation of an ISO 8601 datetime, which the parser accepted
+fn iso_notation(input: &str, date: &Date) -> IsoNotation {
+    let date_form = match date {
+        Date::YMD { .. } => DateForm::Calendar,
+        Date::Week { .. } => DateForm::Week,
+        Date::Ordinal { .. } => DateForm::Ordinal,
+    };
+
+    let (date, time) = input.split_once(['T', 't', ' ']).unwrap_or((input, ""));
+
+    // past the sign of an expanded year, a date only has hyphens in extended notation, while the
+    // hour always has two digits followed by a colon in extended notation
+    let date_notation = match date.trim_start_matches(['+', '-']).contains('-') {
+        true => Notation::Extended,
+        false => Notation::Basic,
+    };
+    let time_notation = match time.as_bytes().get(2) {
+        Some(b':') => Notation::Extended,
+        _ => Notation::Basic,
+    };
+
+    IsoNotation {
+        date: date_form,
+        date_notation,
+        time_notation,
+    }
+}
+
+/// Parses a datetime written in ISO 8601, RFC 3339 or RFC 9557, telling which of them it conforms
+/// to.
+///
+/// When no grammar accepts the input, the error is the one of the grammar that got the furthest
+/// into it.
+///
+/// ## Example
+///
+/// ```rust
+/// let input = "2015-06-26T16:43:23+02:00[Europe/Paris]";
+/// let detected = winnow_datetime_detect::detect(input).unwrap();
+///
+/// assert!(detected.standards.rfc9557);
+/// assert!(!detected.standards.rfc3339);
+/// assert!(detected.datetime.time.time_zone.is_some());
+/// ```
+pub fn detect(input: &str) -> Result<Detected, winnow_datetime::Error> {
+    let rfc9557 = winnow_rfc9557::parse_datetime(input);
+    let rfc3339 = winnow_rfc3339::parse_datetime(input);
+    let iso8601 = winnow_iso8601::parse_datetime(input);
+
+    let standards = Standards {
+        iso8601: iso8601.is_ok(),
+        rfc3339: rfc3339.is_ok(),
+        rfc9557: rfc9557.is_ok(),
+    };
+    let notation = iso8601
+        .as_ref()
+        .ok()
+        .map(|datetime| iso_notation(input, &datetime.date));
+
+    let datetime = match (rfc9557, rfc3339, iso8601) {
+        (Ok(datetime), _, _) | (_, Ok(datetime), _) | (_, _, Ok(datetime)) => datetime,
+        (Err(rfc9557), Err(rfc3339), Err(iso8601)) => {
+            return Err([rfc3339, iso8601]
+                .into_iter()
+                .fold(rfc9557, |furthest, err| {
+                    if err.offset() > furthest.offset() {
+                        err
+                    } else {
+                        furthest
+                    }
+                }))
+        }
+    };
+
+    Ok(Detected {
+        datetime,
+        standards,
+        iso8601: notation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standards(input: &str) -> (bool, bool, bool) {
+        let Standards {
+            iso8601,
+            rfc3339,
+            rfc9557,
+        } = detect(input).unwrap().standards;
+
+        (iso8601, rfc3339, rfc9557)
+    }
+
+    #[test]
+    fn standards_of_input() {
+        assert_eq!(standards("2015-06-26T16:43:23+02:00"), (true, true, true));
+        assert_eq!(standards("2015-06-26t16:43:23.5z"), (false, true, true));
+        assert_eq!(standards("2015-06-26T16:43:23-00:00"), (false, true, true));
+        assert_eq!(
+            standards("2015-06-26T16:43:23+02:00[Europe/Paris][u-ca=iso8601]"),
+            (false, false, true)
+        );
+        assert_eq!(standards("2015-06-26 16:43:23Z"), (true, false, false));
+        assert_eq!(standards("2015-06-26T16:43:23"), (true, false, false));
+    }
+
+    #[test]
+    fn iso_notations() {
+        let notation = |input| detect(input).unwrap().iso8601.unwrap();
+        let iso = |date, date_notation, time_notation| IsoNotation {
+            date,
+            date_notation,
+            time_notation,
+        };
+
+        assert_eq!(
+            notation("20150626T164323Z"),
+            iso(DateForm::Calendar, Notation::Basic, Notation::Basic)
+        );
+        assert_eq!(
+            notation("20150626T16:43:23+0200"),
+            iso(DateForm::Calendar, Notation::Basic, Notation::Extended)
+        );
+        assert_eq!(
+            notation("2015-W26-5T16:43Z"),
+            iso(DateForm::Week, Notation::Extended, Notation::Extended)
+        );
+        assert_eq!(
+            notation("2015177T16"),
+            iso(DateForm::Ordinal, Notation::Basic, Notation::Basic)
+        );
+        assert_eq!(
+            notation("2015-177 16:43:23,5Z"),
+            iso(DateForm::Ordinal, Notation::Extended, Notation::Extended)
+        );
+        assert_eq!(
+            notation("+2015-06-26T16:43:23Z"),
+            iso(DateForm::Calendar, Notation::Extended, Notation::Extended)
+        );
+        assert_eq!(
+            notation("-20150626T164323Z"),
+            iso(DateForm::Calendar, Notation::Basic, Notation::Basic)
+        );
+        assert_eq!(detect("2015-06-26t16:43:23z").unwrap().iso8601, None);
+    }
+
+    #[test]
+    fn value_from_most_specific_grammar() {
+        let detected = detect("2015-06-26T16:43:23Z[u-ca=hebrew]").unwrap();
+        assert!(detected.datetime.time.calendar.is_some());
+
+        let detected = detect("2015-06-26T16:43:23.25Z").unwrap();
+        assert_eq!(detected.datetime.time.nanosecond, 250_000_000);
+    }
+
+    #[test]
+    fn furthest_error() {
+        let err = detect("20150626T164323Zx").unwrap_err();
+        assert_eq!(err.offset(), 16);
+
+        let err = detect("2015-13-26T16:43:23Z").unwrap_err();
+        assert_eq!(err.component(), Some("month"));
+    }
+}
@@ -0,0 +1,601 @@
+//! Adding a [`Duration`] to a [`DateTime`] with calendar semantics.
+//!
+//! Years and months move the date along the calendar, so `2015-01-31` plus one month is the end
+//! of February, while weeks, days and the time components are added exactly. The datetime keeps
+//! its offset: the arithmetic happens on the wall clock time as written.
+//!
+//! [`DateTime::until`] and [`DateTime::since`] go the other way, finding the duration between two
+//! datetimes balanced up to a [`DurationUnit`].
+
+use crate::gregorian;
+use crate::instant::{datetime_key, utc_offset};
+use crate::types::Fraction;
+use crate::{Date, DateTime, Duration, Time};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+/// What to do when adding years or months lands on a day that doesn't exist in the target month,
+/// such as the 31st of January plus one month.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum DayOverflow {
+    /// use the last day of the month, `2015-02-28`
+    #[default]
+    Clamp,
+    /// carry the extra days into the next month, `2015-03-03`
+    RollOver,
+    /// fail, making the checked arithmetic return `None`
+    Reject,
+}
+
+/// The largest unit of the duration returned by [`DateTime::until`], smaller units being carried
+/// into it.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum DurationUnit {
+    /// years and months, then days and time, `P1Y2M3DT4H`
+    Years,
+    /// months, then days and time, `P14M3DT4H`
+    Months,
+    /// weeks, then days and time, `P61W1DT4H`
+    Weeks,
+    /// days and time, `P428DT4H`
+    #[default]
+    Days,
+    /// hours, minutes and seconds, `PT10276H`
+    Hours,
+    /// minutes and seconds, `PT616560M`
+    Minutes,
+    /// seconds only, `PT36993600S`
+    Seconds,
+}
+
+// moves a date by a number of calendar months, handling a day that doesn't exist in the target
+// month as `overflow` says
+fn add_months(date: &Date, months: i64, overflow: DayOverflow) -> Option<Date> {
+    if months == 0 {
+        return Some(*date);
+    }
+
+    let months = (date.year() as i64 * 12 + date.month() as i64 - 1).checked_add(months)?;
+    let year = i32::try_from(months.div_euclid(12)).ok()?;
+    let month = months.rem_euclid(12) as u32 + 1;
+    let last = gregorian::days_in_month(year, month)?;
+
+    let day = date.day();
+    let moved = Date::YMD {
+        year,
+        month,
+        day: day.min(last),
+    };
+
+    match overflow {
+        _ if day <= last => Some(moved),
+        DayOverflow::Clamp => Some(moved),
+        DayOverflow::RollOver => moved.add_days((day - last) as i64),
+        DayOverflow::Reject => None,
+    }
+}
+
+// the number of digits worth keeping for a fraction of a second made by adding two others,
+// `0` to let the writer use as few as possible
+fn fraction_digits(time: &Time, duration: &Duration) -> u8 {
+    match duration.fraction {
+        None => time.fraction_digits,
+        Some(fraction) if fraction.is_zero() => time.fraction_digits,
+        Some(fraction) if fraction.digits == 0 => 0,
+        Some(_) if time.fraction_digits == 0 && time.nanosecond != 0 => 0,
+        Some(fraction) => fraction.digits.max(time.fraction_digits),
+    }
+}
+
+// the day a number of days since the epoch falls on
+fn date_of(days: i128) -> Option<Date> {
+    let (year, day) = gregorian::from_days_since_epoch(i64::try_from(days).ok()?)?;
+
+    Some(Date::Ordinal { year, day })
+}
+
+// nanoseconds since the epoch on the wall clock `offset` seconds behind of UTC
+fn wall_nanos(datetime: &DateTime, offset: i64) -> i128 {
+    let (seconds, nanos) = datetime_key(datetime);
+
+    (seconds as i128 + offset as i128) * NANOS_PER_SECOND + nanos as i128
+}
+
+// the whole months from `start` to `end`, both nanoseconds on the same wall clock, and the
+// nanoseconds left after them. A month only counts if adding it doesn't go past `end` before the
+// day is clamped to the end of the month, so that the 31st of January to the 28th of February is
+// 28 days rather than a month.
+fn month_difference(start: i128, end: i128) -> Option<(i64, i128)> {
+    let sign = if end < start { -1 } else { 1 };
+
+    let mut end_day = end.div_euclid(NANOS_PER_DAY);
+    let mut time = end.rem_euclid(NANOS_PER_DAY) - start.rem_euclid(NANOS_PER_DAY);
+    if time * sign < 0 {
+        end_day -= sign;
+        time += sign * NANOS_PER_DAY;
+    }
+
+    let start = date_of(start.div_euclid(NANOS_PER_DAY))?;
+    let end = date_of(end_day)?;
+
+    let start_month = start.year() as i64 * 12 + start.month() as i64 - 1;
+    let end_month = end.year() as i64 * 12 + end.month() as i64 - 1;
+    let mut months = end_month - start_month;
+    if (start_month + months, start.day()).cmp(&(end_month, end.day())) == sign.cmp(&0) {
+        months -= sign as i64;
+    }
+
+    let middle = add_months(&start, months, DayOverflow::Clamp)?;
+    let days = end_day - gregorian::days_since_epoch(middle.year(), middle.ordinal()) as i128;
+
+    Some((months, days * NANOS_PER_DAY + time))
+}
+
+impl DateTime {
+    /// Adds a duration, returning `None` if the date doesn't exist or the result is out of range.
+    ///
+    /// Years and months are added first, a day that doesn't exist in the target month being
+    /// clamped to the end of that month, then weeks, days and time. A negative duration is
+    /// subtracted. See [`DateTime::checked_add_with`] to handle month ends differently.
+    ///
+    /// A leap second is counted as the first second of the next minute, and the result never has
+    /// a second of 60.
+    ///
+    /// ```
+    /// use winnow_datetime::{Date, DateTime, Duration, Time};
+    ///
+    /// let datetime = DateTime {
+    ///     date: Date::from_ymd(2016, 1, 31).unwrap(),
+    ///     time: Time::from_hms(23, 0, 0).unwrap(),
+    /// };
+    ///
+    /// let later = datetime.checked_add(&Duration::from_ymd(0, 1, 0)).unwrap();
+    /// assert_eq!(later.to_string(), "2016-02-29T23:00:00");
+    ///
+    /// let later = datetime.checked_add(&Duration::from_hms(2, 30, 0)).unwrap();
+    /// assert_eq!(later.to_string(), "2016-02-01T01:30:00");
+    /// ```
+    pub fn checked_add(&self, duration: &Duration) -> Option<DateTime> {
+        self.checked_add_with(duration, DayOverflow::default())
+    }
+
+    /// Subtracts a duration, see [`DateTime::checked_add`].
+    pub fn checked_sub(&self, duration: &Duration) -> Option<DateTime> {
+        self.checked_sub_with(duration, DayOverflow::default())
+    }
+
+    /// Adds a duration, handling days that don't exist in the target month as `overflow` says.
+    ///
+    /// ```
+    /// use winnow_datetime::{DayOverflow, Date, DateTime, Duration};
+    ///
+    /// let datetime = DateTime {
+    ///     date: Date::from_ymd(2015, 1, 31).unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// let month = Duration::from_ymd(0, 1, 0);
+    ///
+    /// let later = datetime.checked_add_with(&month, DayOverflow::RollOver).unwrap();
+    /// assert_eq!(later.date, Date::from_ymd(2015, 3, 3).unwrap());
+    /// assert_eq!(datetime.checked_add_with(&month, DayOverflow::Reject), None);
+    /// ```
+    pub fn checked_add_with(&self, duration: &Duration, overflow: DayOverflow) -> Option<DateTime> {
+        self.date.validate().ok()?;
+
+        let sign = if duration.negative { -1 } else { 1 };
+
+        let months = sign * (duration.years as i64 * 12 + duration.months as i64);
+        let date = add_months(&self.date, months, overflow)?;
+
+        let nanos = sign as i128
+            * ((duration.weeks as i128 * 7 + duration.days as i128) * NANOS_PER_DAY
+                + (duration.hours as i128 * 3600
+                    + duration.minutes as i128 * 60
+                    + duration.seconds as i128)
+                    * NANOS_PER_SECOND
+                + duration.fraction.map_or(0, |f| f.nanos as i128));
+        let time_of_day = (self.time.hour as i128 * 3600
+            + self.time.minute as i128 * 60
+            + self.time.second as i128)
+            * NANOS_PER_SECOND
+            + self.time.nanosecond as i128
+            + nanos;
+
+        let days = i64::try_from(time_of_day.div_euclid(NANOS_PER_DAY)).ok()?;
+        let time_of_day = time_of_day.rem_euclid(NANOS_PER_DAY);
+        let seconds = (time_of_day / NANOS_PER_SECOND) as u32;
+
+        Some(DateTime {
+            date: date.add_days(days)?.to_representation_of(&self.date),
+            time: Time {
+                hour: seconds / 3600,
+                minute: seconds % 3600 / 60,
+                second: seconds % 60,
+                nanosecond: (time_of_day % NANOS_PER_SECOND) as u32,
+                fraction_digits: fraction_digits(&self.time, duration),
+                ..self.time.clone()
+            },
+        })
+    }
+
+    /// Subtracts a duration, handling days that don't exist in the target month as `overflow`
+    /// says.
+    pub fn checked_sub_with(&self, duration: &Duration, overflow: DayOverflow) -> Option<DateTime> {
+        let duration = Duration {
+            negative: !duration.negative,
+            ..*duration
+        };
+
+        self.checked_add_with(&duration, overflow)
+    }
+
+    /// The duration from this datetime to `other`, negative if `other` is earlier, with units
+    /// smaller than `largest` carried into it. Adding the result to this datetime with
+    /// [`DateTime::checked_add`] gives `other` back.
+    ///
+    /// When both datetimes have an offset `other` is first moved to the offset of this one, and
+    /// the difference is taken on the wall clock. Months only count when whole, so the 31st of
+    /// January to the 28th of February is `P28D`. Returns `None` if either date doesn't exist or a
+    /// unit of the result doesn't fit a `u32`.
+    ///
+    /// ```
+    /// use winnow_datetime::{Date, DateTime, Duration, DurationUnit, Offset, Time};
+    ///
+    /// let start = DateTime {
+    ///     date: Date::from_ymd(2015, 1, 31).unwrap(),
+    ///     time: Time {
+    ///         offset: Some(Offset::UTC),
+    ///         ..Time::from_hms(22, 0, 0).unwrap()
+    ///     },
+    /// };
+    /// let end = DateTime {
+    ///     date: Date::from_ymd(2015, 3, 2).unwrap(),
+    ///     time: Time {
+    ///         offset: Some(Offset::from_seconds(7200).unwrap()),
+    ///         ..Time::from_hms(1, 30, 0).unwrap()
+    ///     },
+    /// };
+    ///
+    /// let months = start.until(&end, DurationUnit::Months).unwrap();
+    /// assert_eq!(months.to_string(), "P1M1DT1H30M");
+    ///
+    /// let hours = start.until(&end, DurationUnit::Hours).unwrap();
+    /// assert_eq!(hours.to_string(), "PT697H30M");
+    /// ```
+    pub fn until(&self, other: &DateTime, largest: DurationUnit) -> Option<Duration> {
+        self.date.validate().ok()?;
+        other.date.validate().ok()?;
+
+        let offset = utc_offset(&self.time.offset);
+        let other_offset = match (offset, utc_offset(&other.time.offset)) {
+            (Some(offset), Some(_)) => offset,
+            (_, other_offset) => other_offset.unwrap_or(0),
+        };
+
+        let start = wall_nanos(self, offset.unwrap_or(0));
+        let end = wall_nanos(other, other_offset);
+
+        let (months, nanos) = match largest {
+            DurationUnit::Years | DurationUnit::Months => month_difference(start, end)?,
+            _ => (0, end - start),
+        };
+        let (months, nanos) = (months.unsigned_abs(), nanos.unsigned_abs());
+
+        let (years, months) = match largest {
+            DurationUnit::Years => (months / 12, months % 12),
+            _ => (0, months),
+        };
+        let (days, nanos) = match largest {
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds => (0, nanos),
+            _ => (nanos / NANOS_PER_DAY as u128, nanos % NANOS_PER_DAY as u128),
+        };
+        let (weeks, days) = match largest {
+            DurationUnit::Weeks => (days / 7, days % 7),
+            _ => (0, days),
+        };
+
+        let seconds = nanos / NANOS_PER_SECOND as u128;
+        let (hours, minutes, seconds) = match largest {
+            DurationUnit::Minutes => (0, seconds / 60, seconds % 60),
+            DurationUnit::Seconds => (0, 0, seconds),
+            _ => (seconds / 3600, seconds % 3600 / 60, seconds % 60),
+        };
+        let nanos = (nanos % NANOS_PER_SECOND as u128) as u32;
+
+        Some(Duration {
+            negative: end < start,
+            years: u32::try_from(years).ok()?,
+            months: months as u32,
+            weeks: u32::try_from(weeks).ok()?,
+            days: u32::try_from(days).ok()?,
+            hours: u32::try_from(hours).ok()?,
+            minutes: u32::try_from(minutes).ok()?,
+            seconds: u32::try_from(seconds).ok()?,
+            fraction: (nanos != 0).then(|| Fraction::new(nanos, 0)),
+        })
+    }
+
+    /// The duration from `other` to this datetime, positive if `other` is earlier. This is
+    /// [`DateTime::until`] negated, so subtracting the result from this datetime with
+    /// [`DateTime::checked_sub`] gives `other` back.
+    pub fn since(&self, other: &DateTime, largest: DurationUnit) -> Option<Duration> {
+        let duration = self.until(other, largest)?;
+
+        Some(Duration {
+            negative: !duration.negative && duration != Duration::default(),
+            ..duration
+        })
+    }
+}
+
+impl Duration {
+    /// The signed length of the duration in seconds and nanoseconds, the nanoseconds always
+    /// counting forward so that `-PT0.5S` is `(-1, 500_000_000)`.
+    ///
+    /// Years and months don't have a fixed length: with `relative_to` they are counted exactly
+    /// from that datetime as [`DateTime::checked_add`] does, without it years are counted as 365
+    /// days and months as 30 days. Returns `None` if adding the duration to `relative_to` fails.
+    ///
+    /// ```
+    /// use winnow_datetime::{Date, DateTime, Duration};
+    ///
+    /// let february = DateTime {
+    ///     date: Date::from_ymd(2016, 2, 1).unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// let month = Duration::from_ymd(0, 1, 0);
+    ///
+    /// assert_eq!(month.total_seconds(None), Some((30 * 86_400, 0)));
+    /// assert_eq!(month.total_seconds(Some(&february)), Some((29 * 86_400, 0)));
+    /// ```
+    pub fn total_seconds(&self, relative_to: Option<&DateTime>) -> Option<(i64, u32)> {
+        let nanos = match relative_to {
+            Some(start) => {
+                let end = start.checked_add(self)?;
+                let (start, end) = (datetime_key(start), datetime_key(&end));
+
+                (end.0 as i128 - start.0 as i128) * NANOS_PER_SECOND + end.1 as i128
+                    - start.1 as i128
+            }
+            None => {
+                let nanos = self.nominal_seconds() as i128 * NANOS_PER_SECOND
+                    + self.fraction.map_or(0, |f| f.nanos as i128);
+
+                if self.negative {
+                    -nanos
+                } else {
+                    nanos
+                }
+            }
+        };
+
+        Some((
+            i64::try_from(nanos.div_euclid(NANOS_PER_SECOND)).ok()?,
+            nanos.rem_euclid(NANOS_PER_SECOND) as u32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32) -> DateTime {
+        DateTime {
+            date: Date::from_ymd(year, month, day).unwrap(),
+            time: Time::from_hms(hour, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn add_calendar_units() {
+        assert_eq!(
+            at(2016, 2, 29, 0).checked_add(&Duration::from_ymd(1, 0, 0)),
+            Some(at(2017, 2, 28, 0))
+        );
+        assert_eq!(
+            at(2015, 11, 30, 0).checked_add(&Duration::from_ymd(0, 3, 0)),
+            Some(at(2016, 2, 29, 0))
+        );
+        assert_eq!(
+            at(2015, 3, 31, 0).checked_sub(&Duration::from_ymd(0, 1, 0)),
+            Some(at(2015, 2, 28, 0))
+        );
+        assert_eq!(
+            at(2015, 3, 31, 0)
+                .checked_sub_with(&Duration::from_ymd(0, 1, 0), DayOverflow::RollOver),
+            Some(at(2015, 3, 3, 0))
+        );
+        assert_eq!(
+            at(2015, 12, 31, 0).checked_add(&Duration::from_weeks(1)),
+            Some(at(2016, 1, 7, 0))
+        );
+    }
+
+    #[test]
+    fn add_time_units() {
+        let datetime = DateTime {
+            time: Time::from_hms_milli(23, 59, 59, 500).unwrap(),
+            ..at(2016, 12, 31, 0)
+        };
+        let half_second = Duration {
+            fraction: Some(Fraction::new(500_000_000, 1)),
+            ..Default::default()
+        };
+
+        let later = datetime.checked_add(&half_second).unwrap();
+        assert_eq!(later.to_string(), "2017-01-01T00:00:00.000");
+
+        let earlier = at(2017, 1, 1, 0).checked_sub(&half_second).unwrap();
+        assert_eq!(earlier.to_string(), "2016-12-31T23:59:59.5");
+
+        let negative = Duration {
+            negative: true,
+            ..Duration::from_hms(25, 0, 0)
+        };
+        assert_eq!(
+            at(2017, 1, 1, 0).checked_add(&negative),
+            Some(at(2016, 12, 30, 23))
+        );
+    }
+
+    #[test]
+    fn keeps_representation_and_offset() {
+        let datetime = DateTime {
+            date: Date::from_iso_week(2020, 53, 5).unwrap(),
+            time: Time {
+                offset: Some(crate::Offset::UTC),
+                ..Time::from_hms(12, 0, 0).unwrap()
+            },
+        };
+
+        let later = datetime.checked_add(&Duration::from_ymd(0, 0, 3)).unwrap();
+        assert_eq!(later.to_string(), "2021-W01-1T12:00:00Z");
+    }
+
+    #[test]
+    fn out_of_range() {
+        let invalid = DateTime {
+            date: Date::YMD {
+                year: 2015,
+                month: 2,
+                day: 29,
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(invalid.checked_add(&Duration::from_ymd(0, 0, 1)), None);
+        assert_eq!(
+            at(i32::MAX, 12, 31, 0).checked_add(&Duration::from_ymd(0, 0, 1)),
+            None
+        );
+        assert_eq!(
+            at(2015, 1, 1, 0).checked_add(&Duration::from_ymd(u32::MAX, 0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn total_seconds() {
+        let negative = Duration {
+            negative: true,
+            fraction: Some(Fraction::new(500_000_000, 1)),
+            ..Default::default()
+        };
+        assert_eq!(negative.total_seconds(None), Some((-1, 500_000_000)));
+
+        let year = Duration::from_ymd(1, 0, 0);
+        assert_eq!(year.total_seconds(None), Some((365 * 86_400, 0)));
+        assert_eq!(
+            year.total_seconds(Some(&at(2016, 1, 1, 0))),
+            Some((366 * 86_400, 0))
+        );
+
+        let back = Duration {
+            negative: true,
+            ..Duration::from_ymd(0, 1, 0)
+        };
+        assert_eq!(
+            back.total_seconds(Some(&at(2016, 3, 1, 0))),
+            Some((-29 * 86_400, 0))
+        );
+
+        let centuries = Duration::from_ymd(u32::MAX, 0, 0);
+        assert_eq!(
+            centuries.total_seconds(None),
+            Some((u32::MAX as i64 * 365 * 86_400, 0))
+        );
+        assert_eq!(centuries.total_seconds(Some(&at(2016, 1, 1, 0))), None);
+    }
+
+    #[test]
+    fn until_calendar_units() {
+        let cases = [
+            ((2015, 1, 31, 0), (2015, 2, 28, 0), "P28D"),
+            ((2015, 1, 31, 0), (2015, 3, 1, 0), "P1M1D"),
+            ((2015, 3, 31, 0), (2015, 2, 28, 0), "-P1M"),
+            ((2015, 3, 1, 0), (2015, 1, 31, 0), "-P1M1D"),
+            ((2015, 3, 1, 12), (2015, 4, 1, 6), "P30DT18H"),
+            ((2016, 2, 29, 0), (2020, 2, 29, 1), "P4YT1H"),
+            ((2016, 2, 29, 0), (2019, 2, 28, 0), "P2Y11M30D"),
+            ((2020, 5, 4, 3), (2020, 5, 4, 3), "PT0S"),
+        ];
+
+        for (start, end, expected) in cases {
+            let start = at(start.0, start.1, start.2, start.3);
+            let end = at(end.0, end.1, end.2, end.3);
+
+            let duration = start.until(&end, DurationUnit::Years).unwrap();
+            assert_eq!(duration.to_string(), expected);
+            assert_eq!(start.checked_add(&duration), Some(end.clone()));
+
+            let duration = end.since(&start, DurationUnit::Years).unwrap();
+            assert_eq!(end.checked_sub(&duration), Some(start));
+        }
+    }
+
+    #[test]
+    fn until_largest_unit() {
+        let start = at(2015, 1, 1, 0);
+        let end = DateTime {
+            time: Time::from_hms_milli(1, 2, 3, 500).unwrap(),
+            ..at(2016, 3, 4, 0)
+        };
+
+        let expected = [
+            (DurationUnit::Years, "P1Y2M3DT1H2M3.5S"),
+            (DurationUnit::Months, "P14M3DT1H2M3.5S"),
+            (DurationUnit::Weeks, "P61W1DT1H2M3.5S"),
+            (DurationUnit::Days, "P428DT1H2M3.5S"),
+            (DurationUnit::Hours, "PT10273H2M3.5S"),
+            (DurationUnit::Minutes, "PT616382M3.5S"),
+            (DurationUnit::Seconds, "PT36982923.5S"),
+        ];
+
+        for (unit, expected) in expected {
+            assert_eq!(start.until(&end, unit).unwrap().to_string(), expected);
+        }
+
+        let far = at(-2_000_000, 1, 1, 0);
+        assert_eq!(far.until(&start, DurationUnit::Seconds), None);
+        assert!(far.until(&start, DurationUnit::Years).is_some());
+    }
+
+    #[test]
+    fn until_across_offsets() {
+        let utc = DateTime {
+            time: Time {
+                offset: Some(crate::Offset::UTC),
+                ..Time::from_hms(23, 0, 0).unwrap()
+            },
+            ..at(2015, 6, 30, 0)
+        };
+        let behind = DateTime {
+            time: Time {
+                offset: Some(crate::Offset::from_seconds(-3600).unwrap()),
+                ..Time::from_hms(22, 30, 0).unwrap()
+            },
+            ..at(2015, 7, 30, 0)
+        };
+
+        let duration = utc.until(&behind, DurationUnit::Months).unwrap();
+        assert_eq!(duration.to_string(), "P1MT30M");
+        assert_eq!(
+            utc.checked_add(&duration).unwrap().cmp_instant(&behind),
+            Some(core::cmp::Ordering::Equal)
+        );
+
+        let local = DateTime {
+            time: Time {
+                offset: None,
+                ..behind.time.clone()
+            },
+            ..behind.clone()
+        };
+        assert_eq!(
+            utc.until(&local, DurationUnit::Hours).unwrap().to_string(),
+            "PT719H30M"
+        );
+    }
+}
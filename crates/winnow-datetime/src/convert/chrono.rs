@@ -14,7 +14,10 @@ impl TryFrom<crate::Date> for chrono::NaiveDate {
                 chrono::NaiveDate::from_ymd_opt(year, month, day)
             }
 
-            crate::Date::Week { year, week, day } => chrono::Weekday::from_u32(day)
+            // chrono counts weekdays from 0 for Monday, ISO 8601 from 1
+            crate::Date::Week { year, week, day } => day
+                .checked_sub(1)
+                .and_then(chrono::Weekday::from_u32)
                 .and_then(|d| chrono::NaiveDate::from_isoywd_opt(year, week, d)),
 
             crate::Date::Ordinal { year, day } => chrono::NaiveDate::from_yo_opt(year, day),
@@ -59,7 +62,20 @@ mod test_date {
         let naive = chrono::NaiveDate::try_from(d).unwrap();
         assert_eq!(naive.year(), 2023);
         assert_eq!(naive.month(), 2);
-        assert_eq!(naive.day(), 8);
+        assert_eq!(naive.day(), 7);
+    }
+
+    #[test]
+    fn naivedate_from_week_53() {
+        let d = Date::Week {
+            year: 2020,
+            week: 53,
+            day: 5,
+        };
+        let naive = chrono::NaiveDate::try_from(d).unwrap();
+        assert_eq!(naive.year(), 2021);
+        assert_eq!(naive.month(), 1);
+        assert_eq!(naive.day(), 1);
     }
 
     #[test]
@@ -75,10 +91,15 @@ mod test_date {
     }
 }
 
+/// A leap second is kept, chrono storing it as a nanosecond past 1 000 000 000 of the 59th second.
 impl TryFrom<crate::Time> for chrono::NaiveTime {
     type Error = ();
     fn try_from(t: crate::Time) -> Result<Self, Self::Error> {
-        chrono::NaiveTime::from_hms_opt(t.hour, t.minute, t.second).ok_or(())
+        let (second, nanosecond) = match t.second {
+            60 => (59, t.nanosecond + 1_000_000_000),
+            second => (second, t.nanosecond),
+        };
+        chrono::NaiveTime::from_hms_nano_opt(t.hour, t.minute, second, nanosecond).ok_or(())
     }
 }
 
@@ -87,6 +108,12 @@ impl crate::Time {
     pub fn into_naive(self) -> Option<chrono::NaiveTime> {
         chrono::NaiveTime::try_from(self).ok()
     }
+
+    /// create a [`chrono::NaiveTime`], applying a [`LeapSecond`](crate::LeapSecond) policy
+    /// instead of keeping a leap second, if possible
+    pub fn into_naive_with(self, policy: crate::LeapSecond) -> Option<chrono::NaiveTime> {
+        chrono::NaiveTime::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
 }
 
 impl TryFrom<crate::DateTime> for chrono::DateTime<chrono::FixedOffset> {
@@ -124,6 +151,21 @@ impl TryFrom<crate::DateTime> for chrono::DateTime<chrono::FixedOffset> {
     }
 }
 
+/// Years and months don't have a fixed length, durations using them can't be converted.
+impl TryFrom<crate::Duration> for chrono::TimeDelta {
+    type Error = ();
+
+    fn try_from(d: crate::Duration) -> Result<Self, Self::Error> {
+        if d.years != 0 || d.months != 0 {
+            return Err(());
+        }
+
+        let delta = chrono::TimeDelta::from_std(core::time::Duration::from(d)).or(Err(()))?;
+
+        Ok(if d.negative { -delta } else { delta })
+    }
+}
+
 impl crate::DateTime {
     /// create a [`chrono::DateTime<chrono::FixedOffset>`] if possible
     pub fn into_fixed_offset(self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
@@ -134,6 +176,15 @@ impl crate::DateTime {
     pub fn into_naive(self) -> Option<chrono::NaiveDateTime> {
         self.into_fixed_offset().map(|fxed| fxed.naive_local())
     }
+
+    /// create a [`chrono::DateTime<chrono::FixedOffset>`], applying a
+    /// [`LeapSecond`](crate::LeapSecond) policy instead of keeping a leap second, if possible
+    pub fn into_fixed_offset_with(
+        self,
+        policy: crate::LeapSecond,
+    ) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        chrono::DateTime::<chrono::FixedOffset>::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
 }
 
 #[cfg(test)]
@@ -141,6 +192,22 @@ mod test_datetime {
     use chrono::{Datelike, Offset, Timelike};
     use core::convert::TryFrom;
 
+    #[test]
+    fn time_keeps_nanoseconds() {
+        let iso = crate::Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 123_456_789,
+            fraction_digits: 9,
+            offset: None,
+            time_zone: None,
+            calendar: None,
+        };
+        let time = chrono::NaiveTime::try_from(iso).unwrap();
+        assert_eq!(time.nanosecond(), 123_456_789);
+    }
+
     #[test]
     fn datetime_from_iso_ymd_offset() {
         let dt = crate::DateTime {
@@ -153,7 +220,8 @@ mod test_datetime {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(crate::Offset::Fixed {
                     hours: 1,
                     minutes: 23,
@@ -186,7 +254,8 @@ mod test_datetime {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(crate::Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -219,7 +288,8 @@ mod test_datetime {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(crate::Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -252,7 +322,8 @@ mod test_datetime {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(crate::Offset::Fixed {
                     hours: 1,
                     minutes: 23,
@@ -266,10 +337,61 @@ mod test_datetime {
 
         assert_eq!(datetime.year(), 2023);
         assert_eq!(datetime.month(), 2);
-        assert_eq!(datetime.day(), 8);
+        assert_eq!(datetime.day(), 7);
         assert_eq!(datetime.hour(), 23);
         assert_eq!(datetime.minute(), 40);
         assert_eq!(datetime.second(), 00);
         assert_eq!(datetime.offset().fix().local_minus_utc(), 3623);
     }
+
+    #[test]
+    fn datetime_with_leap_second() {
+        let dt = crate::DateTime {
+            date: crate::Date::from_ymd(2016, 12, 31).unwrap(),
+            time: crate::Time {
+                offset: Some(crate::Offset::UTC),
+                ..crate::Time::from_hms(23, 59, 60).unwrap()
+            },
+        };
+
+        let datetime = chrono::DateTime::try_from(dt.clone()).unwrap();
+        assert_eq!(datetime.year(), 2016);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.nanosecond(), 1_000_000_000);
+
+        let clamped = dt
+            .clone()
+            .into_fixed_offset_with(crate::LeapSecond::Clamp)
+            .unwrap();
+        assert_eq!(clamped.second(), 59);
+        assert_eq!(clamped.nanosecond(), 999_999_999);
+
+        let rolled = dt
+            .clone()
+            .into_fixed_offset_with(crate::LeapSecond::RollOver)
+            .unwrap();
+        assert_eq!(rolled.year(), 2017);
+        assert_eq!(rolled.second(), 0);
+
+        let rejected = dt.time.into_naive_with(crate::LeapSecond::Reject);
+        assert_eq!(rejected, None);
+    }
+
+    #[test]
+    fn timedelta_from_negative_duration() {
+        let d = crate::Duration {
+            negative: true,
+            days: 1,
+            hours: 2,
+            fraction: Some(crate::Fraction::new(500_000_000, 1)),
+            ..Default::default()
+        };
+
+        let delta = chrono::TimeDelta::try_from(d).unwrap();
+        assert_eq!(
+            delta,
+            -chrono::TimeDelta::milliseconds(26 * 3_600_000 + 500)
+        );
+        assert!(chrono::TimeDelta::try_from(crate::Duration::from_ymd(1, 0, 0)).is_err());
+    }
 }
@@ -5,11 +5,13 @@ impl TryFrom<crate::Time> for jiff::civil::Time {
     type Error = jiff::Error;
 
     fn try_from(t: crate::Time) -> Result<Self, Self::Error> {
+        let t = super::leap_second_time(t);
+
         jiff::civil::Time::new(
             t.hour.try_into().unwrap(),
             t.minute.try_into().unwrap(),
             t.second.try_into().unwrap(),
-            t.millisecond.try_into().unwrap(),
+            t.nanosecond.try_into().unwrap(),
         )
     }
 }
@@ -20,6 +22,12 @@ impl crate::Time {
         jiff::civil::Time::try_from(self).ok()
     }
 
+    /// create a [`jiff::civil::Time`], applying a [`LeapSecond`](crate::LeapSecond) policy
+    /// instead of the default, if possible
+    pub fn into_civil_time_with(self, policy: crate::LeapSecond) -> Option<jiff::civil::Time> {
+        jiff::civil::Time::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
+
     pub fn jiff_fixed_tz(o: Option<crate::Offset>) -> Result<jiff::tz::TimeZone, jiff::Error> {
         let o_seconds = if let Some(o) = o {
             match o {
@@ -40,13 +48,19 @@ impl crate::Time {
     }
 }
 
+// jiff years are `i16`s, which an expanded year may not fit
+fn jiff_year(year: i32) -> Result<i16, jiff::Error> {
+    i16::try_from(year)
+        .map_err(|_| jiff::Error::from_args(format_args!("year {} is out of range", year)))
+}
+
 impl TryFrom<crate::Date> for jiff::civil::Date {
     type Error = jiff::Error;
 
     fn try_from(d: crate::Date) -> Result<Self, Self::Error> {
         match d {
             crate::Date::YMD { year, month, day } => {
-                jiff::civil::Date::new(year as i16, month as i8, day.try_into().unwrap())
+                jiff::civil::Date::new(jiff_year(year)?, month as i8, day.try_into().unwrap())
             }
 
             crate::Date::Week { year, week, day } => {
@@ -61,20 +75,16 @@ impl TryFrom<crate::Date> for jiff::civil::Date {
                     _ => panic!("Invalid day of week"),
                 };
 
-                Ok(jiff::civil::ISOWeekDate::new(
-                    year.try_into().unwrap(),
-                    week.try_into().unwrap(),
-                    wd,
-                )?
-                .into())
+                Ok(
+                    jiff::civil::ISOWeekDate::new(jiff_year(year)?, week.try_into().unwrap(), wd)?
+                        .into(),
+                )
             }
 
-            crate::Date::Ordinal { year, day } => {
-                jiff::civil::Date::new(year.try_into().unwrap(), 1, 1)?
-                    .with()
-                    .day_of_year(day.try_into().unwrap())
-                    .build()
-            }
+            crate::Date::Ordinal { year, day } => jiff::civil::Date::new(jiff_year(year)?, 1, 1)?
+                .with()
+                .day_of_year(day.try_into().unwrap())
+                .build(),
         }
     }
 }
@@ -90,6 +100,7 @@ impl TryFrom<crate::DateTime> for jiff::civil::DateTime {
     type Error = jiff::Error;
 
     fn try_from(dt: crate::DateTime) -> Result<Self, Self::Error> {
+        let dt = super::leap_second_datetime(dt);
         let naive_date = jiff::civil::Date::try_from(dt.date)?;
         let naive_time = jiff::civil::Time::try_from(dt.time)?;
 
@@ -101,6 +112,7 @@ impl TryFrom<crate::DateTime> for jiff::Zoned {
     type Error = jiff::Error;
 
     fn try_from(dt: crate::DateTime) -> Result<Self, Self::Error> {
+        let dt = super::leap_second_datetime(dt);
         let naive_date = jiff::civil::Date::try_from(dt.date)?;
         let naive_time = jiff::civil::Time::try_from(dt.time.clone())?;
         let naive_datetime = naive_date.to_datetime(naive_time);
@@ -131,16 +143,25 @@ impl crate::DateTime {
     pub fn into_zoned(self) -> Option<jiff::Zoned> {
         jiff::Zoned::try_from(self).ok()
     }
+
+    /// create a [`jiff::civil::DateTime`], applying a [`LeapSecond`](crate::LeapSecond) policy
+    /// instead of the default, if possible
+    pub fn into_datetime_with(self, policy: crate::LeapSecond) -> Option<jiff::civil::DateTime> {
+        jiff::civil::DateTime::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
+
+    /// create a [`jiff::Zoned`], applying a [`LeapSecond`](crate::LeapSecond) policy instead of
+    /// the default, if possible
+    pub fn into_zoned_with(self, policy: crate::LeapSecond) -> Option<jiff::Zoned> {
+        jiff::Zoned::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
 }
 
 impl TryFrom<crate::Duration> for jiff::Span {
     type Error = jiff::Error;
 
     fn try_from(d: crate::Duration) -> Result<Self, Self::Error> {
-        let ms = d.milliseconds.unwrap_or(0.0).trunc();
-        let ns = (d.milliseconds.unwrap_or(0.0).fract() * 1_000_000.0).round();
-
-        Ok(jiff::Span::new()
+        let span = jiff::Span::new()
             .years(d.years)
             .months(d.months)
             .weeks(d.weeks)
@@ -148,8 +169,9 @@ impl TryFrom<crate::Duration> for jiff::Span {
             .hours(d.hours)
             .minutes(d.minutes)
             .seconds(d.seconds)
-            .milliseconds(ms as i64)
-            .nanoseconds(ns as i64))
+            .nanoseconds(d.fraction.map_or(0, |f| f.nanos));
+
+        Ok(if d.negative { span.negate() } else { span })
     }
 }
 
@@ -159,13 +181,47 @@ mod date_and_time {
     use crate::TimeZone;
     use core::convert::TryFrom;
 
+    #[test]
+    fn date_from_week_53() {
+        let iso = crate::Date::Week {
+            year: 2020,
+            week: 53,
+            day: 5,
+        };
+        let date = jiff::civil::Date::try_from(iso).unwrap();
+        assert_eq!(date, jiff::civil::date(2021, 1, 1));
+    }
+
+    #[test]
+    fn date_with_expanded_year() {
+        for iso in [
+            crate::Date::YMD {
+                year: 40000,
+                month: 1,
+                day: 1,
+            },
+            crate::Date::Week {
+                year: -40000,
+                week: 1,
+                day: 1,
+            },
+            crate::Date::Ordinal {
+                year: 40000,
+                day: 1,
+            },
+        ] {
+            assert!(jiff::civil::Date::try_from(iso).is_err());
+        }
+    }
+
     #[test]
     fn time_from_hms() {
         let iso = crate::Time {
             hour: 23,
             minute: 40,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Default::default(),
             time_zone: None,
             calendar: None,
@@ -176,6 +232,40 @@ mod date_and_time {
         assert_eq!(time.second(), 0);
     }
 
+    #[test]
+    fn time_with_leap_second() {
+        let iso = crate::Time::from_hms(23, 59, 60).unwrap();
+
+        let time = jiff::civil::Time::try_from(iso.clone()).unwrap();
+        assert_eq!(time, jiff::civil::time(23, 59, 59, 999_999_999));
+
+        let time = iso
+            .clone()
+            .into_civil_time_with(crate::LeapSecond::RollOver)
+            .unwrap();
+        assert_eq!(time, jiff::civil::time(0, 0, 0, 0));
+
+        assert!(iso
+            .into_civil_time_with(crate::LeapSecond::Reject)
+            .is_none());
+    }
+
+    #[test]
+    fn time_keeps_nanoseconds() {
+        let iso = crate::Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 123_456_789,
+            fraction_digits: 9,
+            offset: Default::default(),
+            time_zone: None,
+            calendar: None,
+        };
+        let time = jiff::civil::Time::try_from(iso).unwrap();
+        assert_eq!(time.subsec_nanosecond(), 123_456_789);
+    }
+
     #[test]
     fn date_from_ymd() {
         let iso = crate::Date::YMD {
@@ -202,7 +292,8 @@ mod date_and_time {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Default::default(),
                 time_zone: None,
                 calendar: None,
@@ -230,7 +321,8 @@ mod date_and_time {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 2,
                     minutes: 0,
@@ -266,7 +358,8 @@ mod date_and_time {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 2,
                     minutes: 0,
@@ -308,7 +401,8 @@ mod date_and_time {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 2,
                     minutes: 0,
@@ -348,6 +442,7 @@ mod date_and_time {
     #[test]
     fn span_from_duration() {
         let d = crate::Duration {
+            negative: false,
             years: 5,
             months: 4,
             weeks: 3,
@@ -355,7 +450,7 @@ mod date_and_time {
             days: 1,
             minutes: 30,
             seconds: 15,
-            milliseconds: Some(500.544),
+            fraction: Some(crate::types::Fraction::new(500_544_000, 6)),
         };
 
         let s = jiff::Span::try_from(d).unwrap();
@@ -366,7 +461,19 @@ mod date_and_time {
         assert_eq!(s.get_hours(), 2);
         assert_eq!(s.get_minutes(), 30);
         assert_eq!(s.get_seconds(), 15);
-        assert_eq!(s.get_milliseconds(), 500);
-        assert_eq!(s.get_nanoseconds(), 544006);
+        assert_eq!(s.get_nanoseconds(), 500_544_000);
+    }
+
+    #[test]
+    fn span_from_negative_duration() {
+        let d = crate::Duration {
+            negative: true,
+            ..crate::Duration::from_ymd(0, 0, 1)
+        };
+
+        let s = jiff::Span::try_from(d).unwrap();
+
+        assert_eq!(s.get_days(), -1);
+        assert_eq!(s.signum(), -1);
     }
 }
@@ -1,3 +1,12 @@
+//! Conversions to the types of chrono, time and jiff.
+//!
+//! chrono stores a leap second as a nanosecond past 1 000 000 000 of the 59th second, so its
+//! conversions keep one. time and jiff can't represent them, so theirs apply the default
+//! [`LeapSecond::Clamp`](crate::LeapSecond::Clamp), moving a leap second to the last nanosecond of
+//! its minute. Each conversion of a time or datetime also has an `into_*_with` method taking the
+//! [`LeapSecond`](crate::LeapSecond) policy to apply instead, the same way in all three, such as
+//! `DateTime::into_offset_with` for a time `OffsetDateTime`.
+
 #[cfg(feature = "chrono")]
 pub mod chrono;
 
@@ -6,3 +15,18 @@ pub mod jiff;
 
 #[cfg(feature = "time")]
 pub mod time;
+
+// clamps a leap second, which can't fail as it stays within the same minute
+#[cfg(any(feature = "time", feature = "jiff"))]
+pub(crate) fn leap_second_time(time: crate::Time) -> crate::Time {
+    time.with_leap_second(crate::LeapSecond::Clamp)
+        .expect("clamping a leap second always succeeds")
+}
+
+// the same for a datetime, which stays on the same day
+#[cfg(any(feature = "time", feature = "jiff"))]
+pub(crate) fn leap_second_datetime(datetime: crate::DateTime) -> crate::DateTime {
+    datetime
+        .with_leap_second(crate::LeapSecond::Clamp)
+        .expect("clamping a leap second always succeeds")
+}
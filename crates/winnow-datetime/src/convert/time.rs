@@ -4,10 +4,13 @@ impl TryFrom<crate::Time> for time::Time {
     type Error = ();
 
     fn try_from(t: crate::Time) -> Result<Self, Self::Error> {
-        time::Time::from_hms(
+        let t = super::leap_second_time(t);
+
+        time::Time::from_hms_nano(
             t.hour.try_into().unwrap(),
             t.minute.try_into().unwrap(),
             t.second.try_into().unwrap(),
+            t.nanosecond,
         )
         .or(Err(()))
     }
@@ -18,6 +21,12 @@ impl crate::Time {
     pub fn into_time(self) -> Option<time::Time> {
         time::Time::try_from(self).ok()
     }
+
+    /// create a [`time::Time`], applying a [`LeapSecond`](crate::LeapSecond) policy instead of
+    /// the default, if possible
+    pub fn into_time_with(self, policy: crate::LeapSecond) -> Option<time::Time> {
+        time::Time::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
 }
 
 impl TryFrom<crate::Date> for time::Date {
@@ -65,6 +74,7 @@ impl TryFrom<crate::DateTime> for time::PrimitiveDateTime {
     type Error = ();
 
     fn try_from(dt: crate::DateTime) -> Result<Self, Self::Error> {
+        let dt = super::leap_second_datetime(dt);
         let naive_date = time::Date::try_from(dt.date)?;
         let naive_time = time::Time::try_from(dt.time)?;
         Ok(naive_date.with_time(naive_time))
@@ -75,6 +85,7 @@ impl TryFrom<crate::DateTime> for time::OffsetDateTime {
     type Error = ();
 
     fn try_from(dt: crate::DateTime) -> Result<Self, Self::Error> {
+        let dt = super::leap_second_datetime(dt);
         let naive_date = time::Date::try_from(dt.date)?;
         let naive_time = time::Time::try_from(dt.time.clone())?;
 
@@ -103,6 +114,21 @@ impl TryFrom<crate::DateTime> for time::OffsetDateTime {
     }
 }
 
+/// Years and months don't have a fixed length, durations using them can't be converted.
+impl TryFrom<crate::Duration> for time::Duration {
+    type Error = ();
+
+    fn try_from(d: crate::Duration) -> Result<Self, Self::Error> {
+        if d.years != 0 || d.months != 0 {
+            return Err(());
+        }
+
+        let duration = time::Duration::try_from(core::time::Duration::from(d)).or(Err(()))?;
+
+        Ok(if d.negative { -duration } else { duration })
+    }
+}
+
 impl crate::DateTime {
     /// create a [`time::PrimitiveDateTime`] if possible
     pub fn into_primitive(self) -> Option<time::PrimitiveDateTime> {
@@ -112,19 +138,45 @@ impl crate::DateTime {
     pub fn into_offset(self) -> Option<time::OffsetDateTime> {
         time::OffsetDateTime::try_from(self).ok()
     }
+
+    /// create a [`time::PrimitiveDateTime`], applying a [`LeapSecond`](crate::LeapSecond) policy
+    /// instead of the default, if possible
+    pub fn into_primitive_with(self, policy: crate::LeapSecond) -> Option<time::PrimitiveDateTime> {
+        time::PrimitiveDateTime::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
+
+    /// create a [`time::OffsetDateTime`], applying a [`LeapSecond`](crate::LeapSecond) policy
+    /// instead of the default, if possible
+    pub fn into_offset_with(self, policy: crate::LeapSecond) -> Option<time::OffsetDateTime> {
+        time::OffsetDateTime::try_from(self.with_leap_second(policy).ok()?).ok()
+    }
 }
 
 #[cfg(test)]
 mod date_and_time {
     use core::convert::TryFrom;
 
+    #[test]
+    fn date_from_week_53() {
+        let iso = crate::Date::Week {
+            year: 2020,
+            week: 53,
+            day: 5,
+        };
+        let date = time::Date::try_from(iso).unwrap();
+        assert_eq!(date.year(), 2021);
+        assert_eq!(date.month(), time::Month::January);
+        assert_eq!(date.day(), 1);
+    }
+
     #[test]
     fn time_from_hms() {
         let iso = crate::Time {
             hour: 23,
             minute: 40,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Default::default(),
             time_zone: None,
             calendar: None,
@@ -135,6 +187,22 @@ mod date_and_time {
         assert_eq!(time.second(), 0);
     }
 
+    #[test]
+    fn time_keeps_nanoseconds() {
+        let iso = crate::Time {
+            hour: 23,
+            minute: 40,
+            second: 0,
+            nanosecond: 123_456_789,
+            fraction_digits: 9,
+            offset: Default::default(),
+            time_zone: None,
+            calendar: None,
+        };
+        let time = time::Time::try_from(iso).unwrap();
+        assert_eq!(time.nanosecond(), 123_456_789);
+    }
+
     #[test]
     fn date_from_ymd() {
         let iso = crate::Date::YMD {
@@ -161,7 +229,8 @@ mod date_and_time {
                 hour: 23,
                 minute: 40,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Default::default(),
                 time_zone: None,
                 calendar: None,
@@ -176,4 +245,42 @@ mod date_and_time {
         assert_eq!(datetime.minute(), 40);
         assert_eq!(datetime.second(), 0);
     }
+
+    #[test]
+    fn datetime_with_leap_second() {
+        let iso = crate::DateTime {
+            date: crate::Date::from_ymd(2016, 12, 31).unwrap(),
+            time: crate::Time {
+                offset: Some(crate::Offset::UTC),
+                ..crate::Time::from_hms(23, 59, 60).unwrap()
+            },
+        };
+
+        let datetime = time::OffsetDateTime::try_from(iso.clone()).unwrap();
+        assert_eq!(datetime.year(), 2016);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.nanosecond(), 999_999_999);
+
+        let datetime = iso
+            .clone()
+            .into_offset_with(crate::LeapSecond::RollOver)
+            .unwrap();
+        assert_eq!(datetime.year(), 2017);
+        assert_eq!(datetime.second(), 0);
+
+        let rejected = iso.into_primitive_with(crate::LeapSecond::Reject);
+        assert_eq!(rejected, None);
+    }
+
+    #[test]
+    fn duration_from_negative_duration() {
+        let d = crate::Duration {
+            negative: true,
+            ..crate::Duration::from_hms(1, 2, 3)
+        };
+
+        let duration = time::Duration::try_from(d).unwrap();
+        assert_eq!(duration, -time::Duration::seconds(3723));
+        assert!(time::Duration::try_from(crate::Duration::from_ymd(0, 1, 0)).is_err());
+    }
 }
@@ -0,0 +1,552 @@
+//! Canonical [`Display`](core::fmt::Display) implementations for the AST types.
+//!
+//! The output is the extended form shared by the supported formats, e.g. `2015-06-26`,
+//! `16:43:23.123+02:00` or `P1Y2M3DT4H5M6S`, so that a parsed value can be written back out.
+
+use crate::types::{
+    DurationPart, Fraction, IntervalRange, PartialDate, PartialDateTime, PartialTime,
+};
+use crate::writer::{
+    write_calendar_suffix, write_fraction, write_nanosecond, write_numeric_offset, write_offset,
+    write_partial_date, write_time_zone_suffix, write_year, Notation,
+};
+use crate::{
+    Calendar, Date, DateTime, Duration, FractionalDuration, Interval, NamedTimeZone, Offset, Time,
+    TimeZone,
+};
+use core::fmt;
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Date::YMD { year, month, day } => {
+                write_year(f, *year)?;
+                write!(f, "-{:02}-{:02}", month, day)
+            }
+            Date::Week { year, week, day } => {
+                write_year(f, *year)?;
+                write!(f, "-W{:02}-{}", week, day)
+            }
+            Date::Ordinal { year, day } => {
+                write_year(f, *year)?;
+                write!(f, "-{:03}", day)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_offset(f, self, Notation::Extended)
+    }
+}
+
+impl fmt::Display for NamedTimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.identifier)
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeZone::Named { zone } => zone.fmt(f),
+            TimeZone::Fixed { offset } => write_numeric_offset(f, offset, Notation::Extended),
+        }
+    }
+}
+
+impl fmt::Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.identifier)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+
+        write_nanosecond(f, self.nanosecond, self.fraction_digits)?;
+
+        if let Some(offset) = &self.offset {
+            offset.fmt(f)?;
+        }
+
+        if let Some(time_zone) = &self.time_zone {
+            write_time_zone_suffix(f, time_zone)?;
+        }
+
+        if let Some(calendar) = &self.calendar {
+            write_calendar_suffix(f, calendar)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{}", self.date, self.time)
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+
+        if self.is_zero() && self.fraction.is_none() {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+
+        for (value, unit) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if value != 0 {
+                write!(f, "{}{}", value, unit)?;
+            }
+        }
+
+        let has_frac = self.fraction.is_some();
+
+        if self.hours != 0 || self.minutes != 0 || self.seconds != 0 || has_frac {
+            f.write_str("T")?;
+
+            if self.hours != 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+
+            if self.minutes != 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+
+            if self.seconds != 0 || has_frac {
+                write!(f, "{}", self.seconds)?;
+                write_fraction(f, self.fraction)?;
+                f.write_str("S")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for FractionalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is_zero = |(whole, frac): (u32, Option<Fraction>)| whole == 0 && frac.is_none();
+
+        if self.negative {
+            f.write_str("-")?;
+        }
+
+        if [
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+        ]
+        .into_iter()
+        .all(is_zero)
+        {
+            return f.write_str("PT0S");
+        }
+
+        let write_part =
+            |f: &mut fmt::Formatter<'_>, (whole, frac): (u32, Option<Fraction>), unit| {
+                if whole != 0 || frac.is_some() {
+                    write!(f, "{}", whole)?;
+                    write_fraction(f, frac)?;
+                    write!(f, "{}", unit)?;
+                }
+
+                Ok(())
+            };
+
+        f.write_str("P")?;
+
+        write_part(f, self.years, 'Y')?;
+        write_part(f, self.months, 'M')?;
+        write_part(f, self.weeks, 'W')?;
+        write_part(f, self.days, 'D')?;
+
+        if !(is_zero(self.hours) && is_zero(self.minutes) && is_zero(self.seconds)) {
+            f.write_str("T")?;
+
+            write_part(f, self.hours, 'H')?;
+            write_part(f, self.minutes, 'M')?;
+            write_part(f, self.seconds, 'S')?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for DurationPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.whole)?;
+        write_fraction(f, self.frac)
+    }
+}
+
+impl fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_partial_date(f, self, Notation::Extended)
+    }
+}
+
+impl fmt::Display for PartialTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(hour) = self.hour {
+            write!(f, "{:02}", hour)?;
+        }
+
+        if let Some(minute) = self.minute {
+            write!(f, ":{:02}", minute)?;
+        }
+
+        if let Some(second) = self.second {
+            write!(f, ":{:02}", second)?;
+        }
+
+        if let Some(nanosecond) = self.nanosecond {
+            write_nanosecond(f, nanosecond, self.fraction_digits)?;
+        }
+
+        if let Some(offset) = &self.offset {
+            offset.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for PartialDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(date) = &self.date {
+            date.fmt(f)?;
+        }
+
+        if let Some(time) = &self.time {
+            write!(f, "T{}", time)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for IntervalRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalRange::Closed { start, end } => write!(f, "{}/{}", start, end),
+            IntervalRange::ClosedStart { start, duration } => write!(f, "{}/{}", start, duration),
+            IntervalRange::ClosedEnd { duration, end } => write!(f, "{}/{}", duration, end),
+            IntervalRange::Open { duration } => duration.fmt(f),
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repetitions {
+            Some(Some(n)) => write!(f, "R{}/", n)?,
+            Some(None) => f.write_str("R/")?,
+            None => {}
+        }
+
+        self.range.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{Fraction, IntervalRange, PartialDate, PartialDateTime, PartialTime};
+    use crate::{
+        Calendar, Date, DateTime, Duration, FractionalDuration, Interval, NamedTimeZone, Offset,
+        Time, TimeZone,
+    };
+
+    fn time(hour: u32, minute: u32, second: u32, millisecond: u32) -> Time {
+        Time {
+            hour,
+            minute,
+            second,
+            nanosecond: millisecond * 1_000_000,
+            fraction_digits: if millisecond == 0 { 0 } else { 3 },
+            offset: None,
+            time_zone: None,
+            calendar: None,
+        }
+    }
+
+    #[test]
+    fn date() {
+        assert_eq!(
+            Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 26
+            }
+            .to_string(),
+            "2015-06-26"
+        );
+        assert_eq!(
+            Date::Week {
+                year: 2015,
+                week: 5,
+                day: 6
+            }
+            .to_string(),
+            "2015-W05-6"
+        );
+        assert_eq!(
+            Date::Ordinal {
+                year: 2015,
+                day: 56
+            }
+            .to_string(),
+            "2015-056"
+        );
+        assert_eq!(
+            Date::YMD {
+                year: -333,
+                month: 7,
+                day: 11
+            }
+            .to_string(),
+            "-0333-07-11"
+        );
+        assert_eq!(
+            Date::YMD {
+                year: 12015,
+                month: 7,
+                day: 11
+            }
+            .to_string(),
+            "+12015-07-11"
+        );
+    }
+
+    #[test]
+    fn offset() {
+        assert_eq!(
+            Offset::Fixed {
+                hours: 0,
+                minutes: 0,
+                critical: false
+            }
+            .to_string(),
+            "Z"
+        );
+        assert_eq!(
+            Offset::Fixed {
+                hours: 2,
+                minutes: 30,
+                critical: false
+            }
+            .to_string(),
+            "+02:30"
+        );
+        assert_eq!(
+            Offset::Fixed {
+                hours: 0,
+                minutes: -30,
+                critical: false
+            }
+            .to_string(),
+            "-00:30"
+        );
+        assert_eq!(
+            Offset::LocalUnknown { critical: false }.to_string(),
+            "-00:00"
+        );
+    }
+
+    #[test]
+    fn time_with_suffixes() {
+        assert_eq!(time(16, 43, 23, 0).to_string(), "16:43:23");
+        assert_eq!(time(16, 43, 23, 50).to_string(), "16:43:23.050");
+        assert_eq!(
+            Time::from_hms_milli(1, 2, 3, 120).unwrap().to_string(),
+            "01:02:03.120"
+        );
+
+        let mut t = time(2, 4, 28, 0);
+        t.offset = Some(Offset::Fixed {
+            hours: 1,
+            minutes: 0,
+            critical: false,
+        });
+        t.time_zone = Some(TimeZone::Named {
+            zone: NamedTimeZone {
+                identifier: "Europe/Lisbon".to_string(),
+                critical: true,
+            },
+        });
+        t.calendar = Some(Calendar {
+            identifier: "gregory".to_string(),
+            critical: false,
+        });
+
+        assert_eq!(
+            t.to_string(),
+            "02:04:28+01:00[!Europe/Lisbon][u-ca=gregory]"
+        );
+    }
+
+    #[test]
+    fn datetime() {
+        let dt = DateTime {
+            date: Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 26,
+            },
+            time: Time {
+                offset: Some(Offset::Fixed {
+                    hours: -5,
+                    minutes: 0,
+                    critical: false,
+                }),
+                ..time(16, 43, 23, 123)
+            },
+        };
+
+        assert_eq!(dt.to_string(), "2015-06-26T16:43:23.123-05:00");
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(Duration::default().to_string(), "PT0S");
+        assert_eq!(
+            Duration {
+                negative: false,
+                years: 1,
+                months: 2,
+                weeks: 0,
+                days: 3,
+                hours: 4,
+                minutes: 5,
+                seconds: 6,
+                fraction: Some(Fraction::new(500_000_000, 1)),
+            }
+            .to_string(),
+            "P1Y2M3DT4H5M6.5S"
+        );
+        assert_eq!(
+            Duration {
+                weeks: 2,
+                ..Default::default()
+            }
+            .to_string(),
+            "P2W"
+        );
+        assert_eq!(
+            Duration {
+                fraction: Some(Fraction::new(250_000_000, 2)),
+                ..Default::default()
+            }
+            .to_string(),
+            "PT0.25S"
+        );
+        assert_eq!(
+            Duration {
+                fraction: Some(Fraction::new(0, 0)),
+                ..Duration::from_hms(0, 0, 1)
+            }
+            .to_string(),
+            "PT1.0S"
+        );
+        assert_eq!(
+            Duration {
+                negative: true,
+                ..Duration::from_hms(2, 0, 0)
+            }
+            .to_string(),
+            "-PT2H"
+        );
+    }
+
+    #[test]
+    fn fractional_duration() {
+        assert_eq!(
+            FractionalDuration {
+                years: (1, Some(Fraction::new(500_000_000, 1))),
+                hours: (4, Some(Fraction::new(500_000_000, 1))),
+                ..Default::default()
+            }
+            .to_string(),
+            "P1.5YT4.5H"
+        );
+        assert_eq!(
+            FractionalDuration {
+                negative: true,
+                days: (1, Some(Fraction::new(500_000_000, 1))),
+                ..Default::default()
+            }
+            .to_string(),
+            "-P1.5D"
+        );
+    }
+
+    #[test]
+    fn interval() {
+        let start = PartialDateTime {
+            date: Some(PartialDate::YMD {
+                year: Some(2015),
+                month: Some(6),
+                day: Some(25),
+            }),
+            time: Some(PartialTime {
+                hour: Some(12),
+                minute: Some(0),
+                second: None,
+                nanosecond: None,
+                fraction_digits: 0,
+                offset: None,
+            }),
+        };
+
+        let duration = Duration {
+            months: 1,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            Interval {
+                repetitions: Some(Some(5)),
+                range: IntervalRange::ClosedStart { start, duration },
+            }
+            .to_string(),
+            "R5/2015-06-25T12:00/P1M"
+        );
+        assert_eq!(
+            Interval {
+                repetitions: Some(None),
+                range: IntervalRange::Open { duration },
+            }
+            .to_string(),
+            "R/P1M"
+        );
+        assert_eq!(
+            PartialDateTime {
+                date: Some(PartialDate::YWD {
+                    year: Some(2024),
+                    week: Some(51),
+                    day: None,
+                }),
+                time: None,
+            }
+            .to_string(),
+            "2024-W51"
+        );
+    }
+}
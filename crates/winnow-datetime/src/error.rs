@@ -0,0 +1,174 @@
+//! Error returned by the `parse_*` entry points of the format crates.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use winnow::error::{ContextError, ParseError, StrContext, StrContextValue};
+
+/// A failure to parse an input, holding where it happened, which component of the value was being
+/// parsed and what was expected there.
+///
+/// The [`Display`](fmt::Display) implementation points at the failure with a caret:
+///
+/// ```text
+/// invalid month at byte 5, expected two digit month from 01 to 12
+///   2015-13-01
+///        ^
+/// ```
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Error {
+    input: String,
+    offset: usize,
+    // characters before the offset, where the caret goes
+    column: usize,
+    component: Option<&'static str>,
+    expected: Vec<StrContextValue>,
+}
+
+impl Error {
+    /// The input that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Byte offset into the input where parsing failed
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The innermost component being parsed when the failure happened, such as `"month"` or
+    /// `"offset"`
+    pub fn component(&self) -> Option<&'static str> {
+        self.component
+    }
+
+    /// What would have been accepted at [`Error::offset`]
+    pub fn expected(&self) -> &[StrContextValue] {
+        &self.expected
+    }
+}
+
+impl Error {
+    fn new(input: String, offset: usize, column: usize, error: &ContextError) -> Self {
+        let mut component = None;
+        let mut expected = Vec::new();
+
+        // contexts are added while unwinding, innermost first, with each parser's expectations
+        // ahead of its label, so everything up to the first label describes the failing component
+        for context in error.context() {
+            match context {
+                StrContext::Label(label) => {
+                    component = Some(*label);
+                    break;
+                }
+                StrContext::Expected(value) => expected.push(value.clone()),
+                _ => {}
+            }
+        }
+
+        Error {
+            input,
+            offset,
+            column,
+            component,
+            expected,
+        }
+    }
+}
+
+impl From<ParseError<&str, ContextError>> for Error {
+    fn from(e: ParseError<&str, ContextError>) -> Self {
+        let column = e.input()[..e.offset()].chars().count();
+
+        Error::new(e.input().to_string(), e.offset(), column, e.inner())
+    }
+}
+
+/// Bytes that aren't UTF-8 are replaced with `U+FFFD` in [`Error::input`], while
+/// [`Error::offset`] stays a byte offset into the original bytes.
+impl From<ParseError<&[u8], ContextError>> for Error {
+    fn from(e: ParseError<&[u8], ContextError>) -> Self {
+        let column = String::from_utf8_lossy(&e.input()[..e.offset()])
+            .chars()
+            .count();
+
+        Error::new(
+            String::from_utf8_lossy(e.input()).into_owned(),
+            e.offset(),
+            column,
+            e.inner(),
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.component {
+            Some(component) => write!(f, "invalid {} at byte {}", component, self.offset)?,
+            None => write!(f, "invalid input at byte {}", self.offset)?,
+        }
+
+        for (i, expected) in self.expected.iter().enumerate() {
+            if i == 0 {
+                f.write_str(", expected ")?;
+            } else {
+                f.write_str(" or ")?;
+            }
+
+            write!(f, "{}", expected)?;
+        }
+
+        write!(
+            f,
+            "\n  {}\n  {:>width$}",
+            self.input,
+            "^",
+            width = self.column + 1
+        )
+    }
+}
+
+impl core::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use winnow::error::{ContextError, StrContext, StrContextValue};
+    use winnow::Parser;
+
+    #[test]
+    fn caret_display() {
+        let mut parser = "ab"
+            .context(StrContext::Expected(StrContextValue::StringLiteral("ab")))
+            .context(StrContext::Label("greeting"));
+        let e: Error = Parser::<_, _, ContextError>::parse(&mut parser, "xy")
+            .unwrap_err()
+            .into();
+
+        assert_eq!(e.offset(), 0);
+        assert_eq!(e.component(), Some("greeting"));
+        assert_eq!(
+            e.to_string(),
+            "invalid greeting at byte 0, expected `ab`\n  xy\n  ^"
+        );
+    }
+
+    #[test]
+    fn bytes_input() {
+        let mut parser = "ab".context(StrContext::Label("greeting"));
+        let e: Error = Parser::<_, _, ContextError>::parse(&mut parser, &b"\xffab!"[..])
+            .unwrap_err()
+            .into();
+
+        assert_eq!(e.input(), "\u{fffd}ab!");
+        assert_eq!(e.offset(), 0);
+
+        let mut parser = ("\u{e9}", "!").context(StrContext::Label("greeting"));
+        let e: Error = Parser::<_, _, ContextError>::parse(&mut parser, "\u{e9}?".as_bytes())
+            .unwrap_err()
+            .into();
+
+        assert_eq!(e.offset(), 2);
+        assert_eq!(e.to_string(), "invalid greeting at byte 2\n  \u{e9}?\n   ^");
+    }
+}
@@ -0,0 +1,253 @@
+//! Rules of the proleptic Gregorian calendar and the ISO week-numbering year, used to check that
+//! a parsed [`Date`](crate::Date) actually exists.
+//!
+//! The parsers only check that each component is within its widest possible range (a day of
+//! month between 1 and 31 for example), these functions know about month lengths and leap years.
+
+use core::fmt;
+
+/// Reasons a [`Date`](crate::Date) doesn't exist in the calendar.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum InvalidDate {
+    /// the month isn't between 1 and 12
+    Month(u32),
+    /// the day doesn't exist in that month, or in that year for ordinal dates
+    Day(u32),
+    /// the week doesn't exist in that ISO week-numbering year
+    Week(u32),
+    /// the day of week isn't between 1 and 7
+    Weekday(u32),
+}
+
+impl fmt::Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidDate::Month(month) => write!(f, "month {} doesn't exist", month),
+            InvalidDate::Day(day) => write!(f, "day {} doesn't exist", day),
+            InvalidDate::Week(week) => write!(f, "week {} doesn't exist", week),
+            InvalidDate::Weekday(day) => write!(f, "day of week {} doesn't exist", day),
+        }
+    }
+}
+
+impl core::error::Error for InvalidDate {}
+
+/// Whether the year has a 29th of February.
+pub fn is_leap_year(year: i32) -> bool {
+    is_leap(year.into())
+}
+
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Number of days in the year, 365 or 366.
+pub fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+// number of days in a year that may not fit an `i32`
+pub(crate) fn days_in_wide_year(year: i64) -> i64 {
+    if is_leap(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Number of days in the month, or `None` if the month isn't between 1 and 12.
+pub fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// Number of weeks in the ISO week-numbering year, 52 or 53.
+///
+/// A year has 53 weeks when it starts on a Thursday, or when it is a leap year starting on a
+/// Wednesday.
+pub fn weeks_in_year(year: i32) -> u32 {
+    // day of week of the 31st of December, 0 being Sunday
+    fn last_day(year: i64) -> i64 {
+        (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)).rem_euclid(7)
+    }
+
+    let year = year as i64;
+
+    if last_day(year) == 4 || last_day(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+/// Number of days in the year before the first day of the month, or `None` if the month isn't
+/// between 1 and 12.
+pub fn days_before_month(year: i32, month: u32) -> Option<u32> {
+    const DAYS: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+    let days = *DAYS.get((month as usize).checked_sub(1)?)?;
+
+    if month > 2 && is_leap_year(year) {
+        Some(days + 1)
+    } else {
+        Some(days)
+    }
+}
+
+/// Day of week of a day of the year, from 1 for Monday to 7 for Sunday.
+pub fn weekday(year: i32, ordinal: u32) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days_since_epoch(year, ordinal) + 3).rem_euclid(7) as u32 + 1
+}
+
+// days between 1970-01-01 and the given day of the year
+pub(crate) fn days_since_epoch(year: i32, ordinal: u32) -> i64 {
+    fn leap_days_before(year: i64) -> i64 {
+        let year = year - 1;
+        year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)
+    }
+
+    let year = year as i64;
+
+    365 * (year - 1970) + leap_days_before(year) - leap_days_before(1970) + ordinal as i64 - 1
+}
+
+// year and day of the year that are the given number of days after 1970-01-01, or `None` if the
+// year doesn't fit an `i32`
+pub(crate) fn from_days_since_epoch(days: i64) -> Option<(i32, u32)> {
+    // 146097 days in 400 years, which can be one year off around new year
+    let mut year = i32::try_from(1970 + (days * 400).div_euclid(146_097)).ok()?;
+
+    while days < days_since_epoch(year, 1) {
+        year = year.checked_sub(1)?;
+    }
+
+    while days >= days_since_epoch(year, days_in_year(year) + 1) {
+        year = year.checked_add(1)?;
+    }
+
+    Some((year, (days - days_since_epoch(year, 1)) as u32 + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2015));
+        assert_eq!(days_in_month(2024, 2), Some(29));
+        assert_eq!(days_in_month(2015, 2), Some(28));
+        assert_eq!(days_in_month(2015, 13), None);
+    }
+
+    #[test]
+    fn week_53() {
+        for year in [2004, 2009, 2015, 2020, 2026] {
+            assert_eq!(weeks_in_year(year), 53, "{}", year);
+        }
+
+        for year in [2014, 2016, 2019, 2021, 2025] {
+            assert_eq!(weeks_in_year(year), 52, "{}", year);
+        }
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(weekday(1970, 1), 4);
+        assert_eq!(weekday(2000, 1), 6);
+        assert_eq!(weekday(2024, 366), 2);
+        assert_eq!(weekday(0, 1), 6);
+        assert_eq!(weekday(-1, 365), 5);
+        assert_eq!(days_before_month(2024, 3), Some(60));
+        assert_eq!(days_before_month(2023, 0), None);
+    }
+
+    #[test]
+    fn days_since_epoch_round_trip() {
+        for year in [-401, -1, 0, 1969, 1970, 2000, 2024, 9999] {
+            for ordinal in [1, 59, 60, days_in_year(year)] {
+                let days = days_since_epoch(year, ordinal);
+                assert_eq!(from_days_since_epoch(days), Some((year, ordinal)));
+            }
+        }
+
+        assert_eq!(from_days_since_epoch(-1), Some((1969, 365)));
+        assert_eq!(from_days_since_epoch(i64::MAX / 86_400), None);
+    }
+
+    #[test]
+    fn validate_dates() {
+        use crate::Date;
+
+        assert_eq!(
+            Date::YMD {
+                year: 2015,
+                month: 2,
+                day: 29
+            }
+            .validate(),
+            Err(InvalidDate::Day(29))
+        );
+        assert!(Date::YMD {
+            year: 2016,
+            month: 2,
+            day: 29
+        }
+        .validate()
+        .is_ok());
+        assert_eq!(
+            Date::YMD {
+                year: 2015,
+                month: 4,
+                day: 31
+            }
+            .validate(),
+            Err(InvalidDate::Day(31))
+        );
+        assert_eq!(
+            Date::Ordinal {
+                year: 2015,
+                day: 366
+            }
+            .validate(),
+            Err(InvalidDate::Day(366))
+        );
+        assert!(Date::Ordinal {
+            year: 2016,
+            day: 366
+        }
+        .validate()
+        .is_ok());
+        assert!(Date::Week {
+            year: 2015,
+            week: 53,
+            day: 7
+        }
+        .validate()
+        .is_ok());
+        assert_eq!(
+            Date::Week {
+                year: 2016,
+                week: 53,
+                day: 1
+            }
+            .validate(),
+            Err(InvalidDate::Week(53))
+        );
+    }
+}
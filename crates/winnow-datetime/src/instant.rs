@@ -0,0 +1,686 @@
+//! Comparing dates and times by the instant they represent rather than by their fields.
+//!
+//! `2015-06-26T16:43:23+02:00` and `2015-06-26T14:43:23Z` are different values but the same
+//! instant. [`DateTime::cmp_instant`] normalises both to UTC before comparing them, while
+//! [`ByInstant`] gives values a total order so that they can be sorted.
+//!
+//! [`DateTime::to_unix_timestamp`] and [`DateTime::from_unix_timestamp`] convert to and from the
+//! number of seconds since `1970-01-01T00:00:00Z`, and [`Interval::resolve`] gives the start and
+//! end an interval describes.
+
+use crate::gregorian;
+use crate::types::{IntervalRange, PartialDate, PartialDateTime, PartialTime};
+use crate::{Date, DateTime, Interval, InvalidInterval, InvalidTime, Offset, Time};
+use core::cmp::Ordering;
+
+/// Offset from UTC in seconds, with an unknown local offset being UTC as it is in RFC 3339 and
+/// RFC 9557, or `None` for a local time without any offset.
+pub(crate) fn utc_offset(offset: &Option<Offset>) -> Option<i64> {
+    offset.map(|offset| offset.seconds().unwrap_or(0) as i64)
+}
+
+// seconds since midnight in local time
+fn seconds_of_day(time: &Time) -> i64 {
+    time.hour as i64 * 3600 + time.minute as i64 * 60 + time.second as i64
+}
+
+// seconds since the epoch and nanoseconds, treating a missing offset as UTC
+pub(crate) fn datetime_key(datetime: &DateTime) -> (i64, u32) {
+    let days = gregorian::days_since_epoch(datetime.date.year(), datetime.date.ordinal());
+    let offset = utc_offset(&datetime.time.offset).unwrap_or(0);
+
+    (
+        days * 86_400 + seconds_of_day(&datetime.time) - offset,
+        datetime.time.nanosecond,
+    )
+}
+
+// seconds since midnight UTC, which can be negative or over a day, and nanoseconds
+fn time_key(time: &Time) -> (i64, u32) {
+    let offset = utc_offset(&time.offset).unwrap_or(0);
+
+    (seconds_of_day(time) - offset, time.nanosecond)
+}
+
+impl DateTime {
+    /// Compares the instants represented by two datetimes, normalising their offsets to UTC.
+    ///
+    /// Returns `None` if either of them is a local time without an offset. An unknown local
+    /// offset (`-00:00` in RFC 3339, `Z` in RFC 9557) is treated as UTC.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use winnow_datetime::{Date, DateTime, Offset, Time};
+    ///
+    /// let at = |hour, offset| DateTime {
+    ///     date: Date::from_ymd(2015, 6, 26).unwrap(),
+    ///     time: Time {
+    ///         offset,
+    ///         ..Time::from_hms(hour, 0, 0).unwrap()
+    ///     },
+    /// };
+    /// let paris = Some(Offset::from_seconds(7200).unwrap());
+    ///
+    /// assert_eq!(at(16, paris).cmp_instant(&at(15, Some(Offset::UTC))), Some(Ordering::Less));
+    /// assert_eq!(at(16, paris).cmp_instant(&at(14, Some(Offset::UTC))), Some(Ordering::Equal));
+    /// assert_eq!(at(16, paris).cmp_instant(&at(14, None)), None);
+    /// ```
+    pub fn cmp_instant(&self, other: &DateTime) -> Option<Ordering> {
+        utc_offset(&self.time.offset)?;
+        utc_offset(&other.time.offset)?;
+
+        Some(datetime_key(self).cmp(&datetime_key(other)))
+    }
+
+    /// Seconds since `1970-01-01T00:00:00Z` and nanoseconds within that second, or `None` for a
+    /// local time without an offset. An unknown local offset is treated as UTC.
+    ///
+    /// Unix time has no leap seconds, so a leap second is counted as the first second of the
+    /// next minute: `2016-12-31T23:59:60Z` has the same timestamp as `2017-01-01T00:00:00Z`.
+    ///
+    /// ```
+    /// use winnow_datetime::{Date, DateTime, Offset, Time};
+    ///
+    /// let datetime = DateTime {
+    ///     date: Date::from_ymd(2015, 6, 26).unwrap(),
+    ///     time: Time {
+    ///         offset: Some(Offset::from_seconds(7200).unwrap()),
+    ///         ..Time::from_hms_milli(16, 43, 23, 500).unwrap()
+    ///     },
+    /// };
+    ///
+    /// assert_eq!(datetime.to_unix_timestamp(), Some((1_435_329_803, 500_000_000)));
+    /// ```
+    pub fn to_unix_timestamp(&self) -> Option<(i64, u32)> {
+        utc_offset(&self.time.offset)?;
+
+        Some(datetime_key(self))
+    }
+
+    /// Creates the datetime at `secs` seconds and `nanos` nanoseconds after
+    /// `1970-01-01T00:00:00Z`, in the wall clock time of `offset`.
+    ///
+    /// The second is never 60, see [`DateTime::to_unix_timestamp`]. Fails if `nanos` isn't
+    /// less than a second or if the year doesn't fit an `i32`.
+    ///
+    /// ```
+    /// use winnow_datetime::{DateTime, Offset};
+    ///
+    /// let datetime = DateTime::from_unix_timestamp(1_435_329_803, 0, Offset::UTC).unwrap();
+    /// assert_eq!(datetime.to_string(), "2015-06-26T14:43:23Z");
+    /// ```
+    pub fn from_unix_timestamp(
+        secs: i64,
+        nanos: u32,
+        offset: Offset,
+    ) -> Result<DateTime, InvalidTime> {
+        if nanos >= 1_000_000_000 {
+            return Err(InvalidTime::Nanosecond(nanos));
+        }
+
+        let local = secs
+            .checked_add(utc_offset(&Some(offset)).unwrap_or(0))
+            .ok_or(InvalidTime::Timestamp(secs))?;
+        let (year, ordinal) = gregorian::from_days_since_epoch(local.div_euclid(86_400))
+            .ok_or(InvalidTime::Timestamp(secs))?;
+        let seconds = local.rem_euclid(86_400) as u32;
+
+        Ok(DateTime {
+            date: Date::Ordinal { year, day: ordinal }.to_ymd(),
+            time: Time {
+                offset: Some(offset),
+                ..Time::from_hms_nano(seconds / 3600, seconds % 3600 / 60, seconds % 60, nanos)?
+            },
+        })
+    }
+}
+
+impl Time {
+    /// Compares two times of the same day, normalising their offsets to UTC.
+    ///
+    /// Returns `None` if either of them is a local time without an offset.
+    pub fn cmp_instant(&self, other: &Time) -> Option<Ordering> {
+        utc_offset(&self.offset)?;
+        utc_offset(&other.offset)?;
+
+        Some(time_key(self).cmp(&time_key(other)))
+    }
+}
+
+/// Orders and compares the wrapped [`DateTime`] or [`Time`] by the instant it represents.
+///
+/// Values without an offset are treated as if they were in UTC, which makes this a total order:
+/// values sharing the same offset, or having none, are ordered by their wall clock time, values
+/// with different offsets are ordered as [`DateTime::cmp_instant`] does.
+///
+/// ```
+/// use winnow_datetime::{ByInstant, Date, DateTime, Offset, Time};
+///
+/// let at = |hour, hours| DateTime {
+///     date: Date::from_ymd(2015, 6, 26).unwrap(),
+///     time: Time {
+///         offset: Some(Offset::Fixed { hours, minutes: 0, critical: false }),
+///         ..Time::from_hms(hour, 0, 0).unwrap()
+///     },
+/// };
+///
+/// let mut logs = vec![at(16, 2), at(13, 0), at(15, 0)];
+/// logs.sort_by_key(|dt| ByInstant(dt.clone()));
+///
+/// assert_eq!(logs, vec![at(13, 0), at(16, 2), at(15, 0)]);
+/// assert_eq!(ByInstant(at(16, 2)), ByInstant(at(14, 0)));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByInstant<T>(pub T);
+
+impl PartialEq for ByInstant<DateTime> {
+    fn eq(&self, other: &Self) -> bool {
+        datetime_key(&self.0) == datetime_key(&other.0)
+    }
+}
+
+impl Eq for ByInstant<DateTime> {}
+
+impl PartialOrd for ByInstant<DateTime> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByInstant<DateTime> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        datetime_key(&self.0).cmp(&datetime_key(&other.0))
+    }
+}
+
+impl PartialEq for ByInstant<Time> {
+    fn eq(&self, other: &Self) -> bool {
+        time_key(&self.0) == time_key(&other.0)
+    }
+}
+
+impl Eq for ByInstant<Time> {}
+
+impl PartialOrd for ByInstant<Time> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByInstant<Time> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        time_key(&self.0).cmp(&time_key(&other.0))
+    }
+}
+
+// the datetime a start or end of an interval names, midnight if it has no time
+fn resolve(datetime: &PartialDateTime) -> Result<DateTime, InvalidInterval> {
+    let date = match datetime.date.ok_or(InvalidInterval::Incomplete)? {
+        PartialDate::YMD {
+            year: Some(year),
+            month: Some(month),
+            day: Some(day),
+        } => Date::from_ymd(year, month, day),
+        PartialDate::YWD {
+            year: Some(year),
+            week: Some(week),
+            day: Some(day),
+        } => Date::from_iso_week(year, week, day),
+        PartialDate::YDDD {
+            year: Some(year),
+            day: Some(day),
+        } => Date::from_ordinal(year, day),
+        _ => return Err(InvalidInterval::Incomplete),
+    }
+    .map_err(InvalidInterval::Date)?;
+
+    let time = match datetime.time {
+        Some(time) => Time {
+            offset: time.offset,
+            fraction_digits: time.fraction_digits,
+            ..Time::from_hms_nano(
+                time.hour.ok_or(InvalidInterval::Incomplete)?,
+                time.minute.unwrap_or(0),
+                time.second.unwrap_or(0),
+                time.nanosecond.unwrap_or(0),
+            )
+            .map_err(InvalidInterval::Time)?
+        },
+        None => Time::default(),
+    };
+
+    Ok(DateTime { date, time })
+}
+
+// the datetime written at one end of an interval, if it names a single day and time
+fn complete(datetime: &PartialDateTime) -> Option<DateTime> {
+    resolve(datetime).ok()
+}
+
+// an abbreviated end of an interval such as the `26` of `2015-06-25/26`, with the components it
+// leaves out taken from the start. Only components larger than one it gives are taken, and the
+// offset when it has a time without one.
+fn fill_end(start: &PartialDateTime, end: &PartialDateTime) -> PartialDateTime {
+    let date = match (start.date, end.date) {
+        (
+            Some(PartialDate::YMD {
+                year: start_year,
+                month: start_month,
+                ..
+            }),
+            Some(PartialDate::YMD { year, month, day }),
+        ) => Some(PartialDate::YMD {
+            year: year.or(start_year.filter(|_| month.is_some() || day.is_some())),
+            month: month.or(start_month.filter(|_| day.is_some())),
+            day,
+        }),
+        (
+            Some(PartialDate::YWD {
+                year: start_year,
+                week: start_week,
+                ..
+            }),
+            Some(PartialDate::YWD { year, week, day }),
+        ) => Some(PartialDate::YWD {
+            year: year.or(start_year.filter(|_| week.is_some() || day.is_some())),
+            week: week.or(start_week.filter(|_| day.is_some())),
+            day,
+        }),
+        (
+            Some(PartialDate::YDDD {
+                year: start_year, ..
+            }),
+            Some(PartialDate::YDDD { year, day }),
+        ) => Some(PartialDate::YDDD {
+            year: year.or(start_year.filter(|_| day.is_some())),
+            day,
+        }),
+        (start_date, None) if end.time.is_some() => start_date,
+        (_, date) => date,
+    };
+
+    let time = match (start.time, end.time) {
+        (Some(start_time), Some(time)) => Some(PartialTime {
+            offset: time.offset.or(start_time.offset),
+            ..time
+        }),
+        (_, time) => time,
+    };
+
+    PartialDateTime { date, time }
+}
+
+impl IntervalRange {
+    /// The start and end of the interval, see [`Interval::resolve`].
+    pub fn resolve(&self) -> Result<(DateTime, DateTime), InvalidInterval> {
+        match self {
+            IntervalRange::Closed { start, end } => {
+                Ok((resolve(start)?, resolve(&fill_end(start, end))?))
+            }
+            IntervalRange::ClosedStart { start, duration } => {
+                let start = resolve(start)?;
+                let end = start
+                    .checked_add(duration)
+                    .ok_or(InvalidInterval::OutOfRange)?;
+
+                Ok((start, end))
+            }
+            IntervalRange::ClosedEnd { duration, end } => {
+                let end = resolve(end)?;
+                let start = end
+                    .checked_sub(duration)
+                    .ok_or(InvalidInterval::OutOfRange)?;
+
+                Ok((start, end))
+            }
+            IntervalRange::Open { .. } => Err(InvalidInterval::Unanchored),
+        }
+    }
+
+    /// Compares the start and end of a closed interval by the instants they represent.
+    ///
+    /// Ends without an offset are compared by their wall clock time when neither of them has
+    /// one. Returns `None` for intervals given with a duration, when an end doesn't name a full
+    /// date, or when only one end has an offset.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use winnow_datetime::types::{IntervalRange, PartialDate, PartialDateTime};
+    ///
+    /// let day = |day| PartialDateTime {
+    ///     date: Some(PartialDate::YMD { year: Some(2007), month: Some(3), day: Some(day) }),
+    ///     time: None,
+    /// };
+    ///
+    /// let range = IntervalRange::Closed { start: day(1), end: day(11) };
+    /// assert_eq!(range.cmp_bounds(), Some(Ordering::Less));
+    /// ```
+    pub fn cmp_bounds(&self) -> Option<Ordering> {
+        let IntervalRange::Closed { start, end } = self else {
+            return None;
+        };
+        let (start, end) = (complete(start)?, complete(end)?);
+
+        match (start.time.offset, end.time.offset) {
+            (None, None) => Some(ByInstant(start).cmp(&ByInstant(end))),
+            _ => start.cmp_instant(&end),
+        }
+    }
+}
+
+impl Interval {
+    /// The start and end the interval describes.
+    ///
+    /// An abbreviated end such as the `26` of `2015-06-25/26` takes the components it leaves out
+    /// from the start, as does an end time without an offset, and a duration is added to the start
+    /// or subtracted from the end with [`DateTime::checked_add`]. A start or end without a time is
+    /// midnight, and the repetitions are ignored.
+    ///
+    /// Fails if a start or end doesn't name a full date, such as `2015-06`, or doesn't exist, and
+    /// if the interval is only a duration.
+    ///
+    /// ```
+    /// use winnow_datetime::types::{Interval, IntervalRange, PartialDate, PartialDateTime};
+    /// use winnow_datetime::{Date, Duration, InvalidInterval};
+    ///
+    /// let start = PartialDateTime {
+    ///     date: Some(PartialDate::YMD { year: Some(2015), month: Some(6), day: Some(25) }),
+    ///     time: None,
+    /// };
+    /// let end = PartialDateTime {
+    ///     date: Some(PartialDate::YMD { year: None, month: None, day: Some(26) }),
+    ///     time: None,
+    /// };
+    ///
+    /// let interval = Interval { repetitions: None, range: IntervalRange::Closed { start, end } };
+    /// let (_, end) = interval.resolve().unwrap();
+    /// assert_eq!(end.date, Date::from_ymd(2015, 6, 26).unwrap());
+    ///
+    /// let duration = Duration::from_ymd(0, 0, 1);
+    /// let open = Interval { repetitions: None, range: IntervalRange::Open { duration } };
+    /// assert_eq!(open.resolve(), Err(InvalidInterval::Unanchored));
+    /// ```
+    pub fn resolve(&self) -> Result<(DateTime, DateTime), InvalidInterval> {
+        self.range.resolve()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Duration;
+
+    fn time(hour: u32, offset: Option<Offset>) -> Time {
+        Time {
+            offset,
+            ..Time::from_hms(hour, 0, 0).unwrap()
+        }
+    }
+
+    #[test]
+    fn time_cmp_instant() {
+        let tokyo = Some(Offset::from_seconds(9 * 3600).unwrap());
+        let unknown = Some(Offset::LocalUnknown { critical: false });
+
+        assert_eq!(
+            time(8, tokyo).cmp_instant(&time(0, Some(Offset::UTC))),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            time(9, tokyo).cmp_instant(&time(0, unknown)),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(time(9, tokyo).cmp_instant(&time(0, None)), None);
+        assert!(ByInstant(time(8, tokyo)) < ByInstant(time(0, None)));
+    }
+
+    #[test]
+    fn datetime_cmp_instant_across_days() {
+        let at = |day, hour, offset| DateTime {
+            date: Date::from_ymd(2015, 12, day).unwrap(),
+            time: time(hour, offset),
+        };
+        let new_york = Some(Offset::from_seconds(-5 * 3600).unwrap());
+
+        assert_eq!(
+            at(31, 20, new_york).cmp_instant(&at(31, 23, Some(Offset::UTC))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            at(31, 19, new_york).cmp_instant(&at(1, 0, Some(Offset::UTC))),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            ByInstant(at(31, 19, new_york)),
+            ByInstant(DateTime {
+                date: Date::from_ymd(2016, 1, 1).unwrap(),
+                time: time(0, Some(Offset::UTC)),
+            })
+        );
+    }
+
+    #[test]
+    fn unix_timestamps() {
+        let at = |year, month, day, time: Time| DateTime {
+            date: Date::from_ymd(year, month, day).unwrap(),
+            time,
+        };
+        let utc = |hour, minute, second| Time {
+            offset: Some(Offset::UTC),
+            ..Time::from_hms(hour, minute, second).unwrap()
+        };
+
+        assert_eq!(
+            at(1970, 1, 1, utc(0, 0, 0)).to_unix_timestamp(),
+            Some((0, 0))
+        );
+        assert_eq!(
+            at(1969, 12, 31, utc(23, 59, 59)).to_unix_timestamp(),
+            Some((-1, 0))
+        );
+        assert_eq!(
+            at(2016, 12, 31, utc(23, 59, 60)).to_unix_timestamp(),
+            at(2017, 1, 1, utc(0, 0, 0)).to_unix_timestamp()
+        );
+        assert_eq!(at(2016, 12, 31, time(23, None)).to_unix_timestamp(), None);
+
+        for secs in [
+            -62_135_596_800,
+            -1,
+            0,
+            951_782_400,
+            1_483_228_799,
+            253_402_300_799,
+        ] {
+            for offset in [Offset::UTC, Offset::from_seconds(-34_200).unwrap()] {
+                let datetime = DateTime::from_unix_timestamp(secs, 999_999_999, offset).unwrap();
+
+                assert!(datetime.date.validate().is_ok());
+                assert_eq!(datetime.to_unix_timestamp(), Some((secs, 999_999_999)));
+            }
+        }
+
+        assert_eq!(
+            DateTime::from_unix_timestamp(951_782_400, 0, Offset::UTC),
+            Ok(at(2000, 2, 29, utc(0, 0, 0)))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(-1, 0, Offset::from_seconds(3600).unwrap())
+                .unwrap()
+                .to_string(),
+            "1970-01-01T00:59:59+01:00"
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(0, 1_000_000_000, Offset::UTC),
+            Err(InvalidTime::Nanosecond(1_000_000_000))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(i64::MAX, 0, Offset::from_seconds(60).unwrap()),
+            Err(InvalidTime::Timestamp(i64::MAX))
+        );
+        assert_eq!(
+            DateTime::from_unix_timestamp(i64::MIN / 2, 0, Offset::UTC),
+            Err(InvalidTime::Timestamp(i64::MIN / 2))
+        );
+    }
+
+    #[test]
+    fn interval_cmp_bounds() {
+        let at = |day, hour, offset| PartialDateTime {
+            date: Some(PartialDate::YMD {
+                year: Some(2007),
+                month: Some(3),
+                day: Some(day),
+            }),
+            time: Some(PartialTime {
+                hour: Some(hour),
+                minute: None,
+                second: None,
+                nanosecond: None,
+                fraction_digits: 0,
+                offset,
+            }),
+        };
+        let paris = Some(Offset::from_seconds(3600).unwrap());
+        let closed = |start, end| IntervalRange::Closed { start, end };
+
+        assert_eq!(
+            closed(at(1, 13, None), at(1, 12, None)).cmp_bounds(),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            closed(at(1, 13, paris), at(1, 12, Some(Offset::UTC))).cmp_bounds(),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(closed(at(1, 13, paris), at(1, 14, None)).cmp_bounds(), None);
+        assert_eq!(
+            closed(
+                at(1, 13, None),
+                PartialDateTime {
+                    date: None,
+                    ..at(1, 14, None)
+                }
+            )
+            .cmp_bounds(),
+            None
+        );
+    }
+
+    #[test]
+    fn interval_resolve() {
+        let ymd = |year, month, day| PartialDate::YMD { year, month, day };
+        let at = |date, hour: Option<u32>, offset| PartialDateTime {
+            date,
+            time: hour.map(|hour| PartialTime {
+                hour: Some(hour),
+                minute: Some(30),
+                second: None,
+                nanosecond: None,
+                fraction_digits: 0,
+                offset,
+            }),
+        };
+        let expected = |day, hour, offset| DateTime {
+            date: Date::from_ymd(2007, 12, day).unwrap(),
+            time: Time {
+                offset,
+                ..Time::from_hms(hour, 30, 0).unwrap()
+            },
+        };
+        let start = at(
+            Some(ymd(Some(2007), Some(12), Some(14))),
+            Some(13),
+            Some(Offset::UTC),
+        );
+
+        let closed = IntervalRange::Closed {
+            start,
+            end: at(Some(ymd(None, None, Some(15))), Some(15), None),
+        };
+        assert_eq!(
+            closed.resolve(),
+            Ok((
+                expected(14, 13, Some(Offset::UTC)),
+                expected(15, 15, Some(Offset::UTC))
+            ))
+        );
+
+        let time_only = IntervalRange::Closed {
+            start,
+            end: at(None, Some(15), None),
+        };
+        assert_eq!(
+            time_only.resolve(),
+            Ok((
+                expected(14, 13, Some(Offset::UTC)),
+                expected(14, 15, Some(Offset::UTC))
+            ))
+        );
+
+        let duration = Duration::from_hms(36, 0, 0);
+        assert_eq!(
+            IntervalRange::ClosedStart { start, duration }.resolve(),
+            Ok((
+                expected(14, 13, Some(Offset::UTC)),
+                expected(16, 1, Some(Offset::UTC))
+            ))
+        );
+        assert_eq!(
+            IntervalRange::ClosedEnd {
+                duration,
+                end: start
+            }
+            .resolve(),
+            Ok((
+                expected(13, 1, Some(Offset::UTC)),
+                expected(14, 13, Some(Offset::UTC))
+            ))
+        );
+    }
+
+    #[test]
+    fn interval_resolve_errors() {
+        let at = |date| PartialDateTime { date, time: None };
+        let start = at(Some(PartialDate::YWD {
+            year: Some(2015),
+            week: Some(53),
+            day: Some(7),
+        }));
+
+        let incomplete = IntervalRange::Closed {
+            start,
+            end: at(Some(PartialDate::YWD {
+                year: Some(2016),
+                week: None,
+                day: None,
+            })),
+        };
+        assert_eq!(incomplete.resolve(), Err(InvalidInterval::Incomplete));
+
+        let missing = IntervalRange::Closed {
+            start,
+            end: at(Some(PartialDate::YWD {
+                year: None,
+                week: Some(54),
+                day: Some(1),
+            })),
+        };
+        assert_eq!(
+            missing.resolve(),
+            Err(InvalidInterval::Date(crate::gregorian::InvalidDate::Week(
+                54
+            )))
+        );
+
+        let too_long = IntervalRange::ClosedStart {
+            start,
+            duration: Duration::from_ymd(u32::MAX, 0, 0),
+        };
+        assert_eq!(too_long.resolve(), Err(InvalidInterval::OutOfRange));
+
+        let open = IntervalRange::Open {
+            duration: Duration::from_weeks(1),
+        };
+        assert_eq!(open.resolve(), Err(InvalidInterval::Unanchored));
+    }
+}
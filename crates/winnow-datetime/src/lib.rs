@@ -1,19 +1,37 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 extern crate core;
 
+pub mod arithmetic;
 mod clippy;
-#[cfg(any(feature = "time", feature = "chrono"))]
+#[cfg(any(feature = "time", feature = "chrono", feature = "jiff"))]
 pub mod convert;
+mod display;
+pub mod error;
+pub mod gregorian;
+pub mod instant;
 mod macros;
 pub mod parser;
+pub mod recurrence;
+pub mod scan;
 pub mod types;
-pub mod util;
+pub mod writer;
 
+pub use arithmetic::{DayOverflow, DurationUnit};
+pub use error::Error;
+pub use instant::ByInstant;
+pub use recurrence::Occurrences;
 pub use types::Calendar;
 pub use types::Date;
 pub use types::DateTime;
 pub use types::Duration;
+pub use types::Fraction;
 pub use types::FractionalDuration;
 pub use types::Interval;
+pub use types::InvalidInterval;
+pub use types::InvalidTime;
+pub use types::LeapSecond;
 pub use types::NamedTimeZone;
 pub use types::Offset;
 pub use types::Time;
@@ -6,18 +6,18 @@ macro_rules! duration_part_seq {
         fraction: $fraction_parser:expr,
         end: $end_parser:expr
     }) => {
-        move |input: &mut _| {
+        (move |input: &mut _| {
             seq!((
                 $whole_parser,
-                opt(preceded($sep_parser, $fraction_parser.map(|n| {
-                   winnow_datetime::util::digits_to_fractional_f32(n)
+                opt(preceded($sep_parser, $fraction_parser.map(|(nanos, digits)| {
+                   winnow_datetime::types::Fraction { nanos, digits }
                 }
                 ))),
                 _ : $end_parser
             ))
             .map(move |(whole, frac)| DurationPart{ whole, frac })
             .parse_next(input)
-        }
+        })
     };
 }
 
@@ -28,13 +28,13 @@ macro_rules! date_yddd_seq {
         year: $year_parser:expr,
         day: $day_parser:expr,
     }) => {
-        move |input: &mut _| {
+        (move |input: &mut _| {
             seq!($date_type::$variant {
                 year: $year_parser,
                 day: $day_parser,
             })
             .parse_next(input)
-        }
+        })
     };
 }
 
@@ -77,20 +77,34 @@ macro_rules! time_seq {
         hour: $hour_parser:expr,
         minute: $minute_parser:expr,
         second: $second_parser:expr,
-        millisecond: $millisecond_parser:expr,
+        fraction: $fraction_parser:expr,
         offset: $offset_parser:expr,
         time_zone: $time_zone_parser:expr,
         calendar: $calendar_parser:expr,
     }) => {
-        seq!($variant {
-            hour: $hour_parser,
-            minute: $minute_parser,
-            second: $second_parser,
-            millisecond: $millisecond_parser,
-            offset: $offset_parser,
-            time_zone: $time_zone_parser,
-            calendar: $calendar_parser,
-        })
+        seq!((
+            $hour_parser,
+            $minute_parser,
+            $second_parser,
+            $fraction_parser,
+            $offset_parser,
+            $time_zone_parser,
+            $calendar_parser,
+        ))
+        .map(
+            |(hour, minute, second, (nanosecond, fraction_digits), offset, time_zone, calendar)| {
+                $variant {
+                    hour,
+                    minute,
+                    second,
+                    nanosecond,
+                    fraction_digits,
+                    offset,
+                    time_zone,
+                    calendar,
+                }
+            },
+        )
     };
 }
 
@@ -101,15 +115,25 @@ macro_rules! partial_time_seq {
         hour: $hour_parser:expr,
         minute: $minute_parser:expr,
         second: $second_parser:expr,
-        millisecond: $millisecond_parser:expr,
+        fraction: $fraction_parser:expr,
         offset: $offset_parser:expr,
     }) => {
-        seq!($variant {
-            hour: $hour_parser,
-            minute: $minute_parser,
-            second: $second_parser,
-            millisecond: $millisecond_parser,
-            offset: $offset_parser,
-        })
+        seq!((
+            $hour_parser,
+            $minute_parser,
+            $second_parser,
+            $fraction_parser,
+            $offset_parser,
+        ))
+        .map(
+            |(hour, minute, second, fraction, offset): (_, _, _, Option<(u32, u8)>, _)| $variant {
+                hour,
+                minute,
+                second,
+                nanosecond: fraction.map(|(nanosecond, _)| nanosecond),
+                fraction_digits: fraction.map_or(0, |(_, digits)| digits),
+                offset,
+            },
+        )
     };
 }
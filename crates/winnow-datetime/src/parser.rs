@@ -3,14 +3,123 @@
 //! Using the low-level functions provided here allows to recover leftover input
 //! or to combine these parsers with other parser combinators.
 
+use alloc::string::String;
+use core::ops::RangeBounds;
 use core::str;
-use std::ops::RangeBounds;
 use winnow::ascii::{digit1, Int, Uint};
-use winnow::combinator::{alt, trace};
-use winnow::error::ParserError;
+use winnow::combinator::{alt, fail, trace};
+use winnow::error::{AddContext, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::{literal, take_while};
 use winnow::Parser;
+
+use crate::Date;
+
+/// Alternatives for [`furthest`], a tuple of parsers with the same output
+pub trait Furthest<Input, Output, Error> {
+    /// Tries each alternative in turn, see [`furthest`]
+    fn choice(&mut self, input: &mut Input) -> Result<Output, Error>;
+}
+
+macro_rules! furthest_tuple {
+    ($($parser:ident $index:tt),+) => {
+        impl<Input, Output, Error, $($parser),+> Furthest<Input, Output, Error> for ($($parser,)+)
+        where
+            Input: Stream,
+            Error: ParserError<Input>,
+            $($parser: Parser<Input, Output, Error>,)+
+        {
+            fn choice(&mut self, input: &mut Input) -> Result<Output, Error> {
+                let start = input.checkpoint();
+                let mut furthest: Option<(usize, Error, <Input as Stream>::Checkpoint)> = None;
+
+                $(
+                    input.reset(&start);
+                    match self.$index.parse_next(input) {
+                        Ok(output) => return Ok(output),
+                        Err(e) if !e.is_backtrack() => return Err(e),
+                        Err(e) => {
+                            let offset = input.offset_from(&start);
+
+                            // the last to get as far, or the first when none got anywhere
+                            let further = |(furthest, _, _): &(usize, _, _)| {
+                                offset > *furthest || (offset == *furthest && offset > 0)
+                            };
+
+                            if furthest.as_ref().is_none_or(further) {
+                                furthest = Some((offset, e, input.checkpoint()));
+                            }
+                        }
+                    }
+                )+
+
+                let (_, e, at) = furthest.expect("at least one alternative");
+                input.reset(&at);
+                Err(e)
+            }
+        }
+    };
+}
+
+furthest_tuple!(P0 0, P1 1);
+furthest_tuple!(P0 0, P1 1, P2 2);
+furthest_tuple!(P0 0, P1 1, P2 2, P3 3);
+furthest_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4);
+furthest_tuple!(P0 0, P1 1, P2 2, P3 3, P4 4, P5 5);
+
+/// Tries each alternative like [`alt`], but when they all fail returns the error of the one that
+/// got the furthest into the input, the last one among those that got as far. When none of them
+/// got anywhere, the error is the one of the first.
+///
+/// The error then describes the component of the alternative the input most likely meant, such
+/// as the week of `2021-W53-5` rather than the month of a calendar date.
+pub fn furthest<Input, Output, Error, Alternatives>(
+    mut alternatives: Alternatives,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input>,
+    Alternatives: Furthest<Input, Output, Error>,
+{
+    trace("furthest", move |input: &mut Input| {
+        alternatives.choice(input)
+    })
+}
+
+/// Runs a date parser, and when the date doesn't exist in the calendar fails at its day, the last
+/// component written, as for the `29` of `2015-02-29`. See [`Date::validate`] for the checks.
+pub fn existing_date<Input, Error, ParseDate>(
+    mut date: ParseDate,
+) -> impl Parser<Input, Date, Error>
+where
+    Input: Stream,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+    ParseDate: Parser<Input, Date, Error>,
+{
+    trace("existing_date", move |input: &mut Input| {
+        let start = input.checkpoint();
+        let parsed = date.parse_next(input)?;
+
+        if parsed.validate().is_ok() {
+            return Ok(parsed);
+        }
+
+        let (digits, expected) = match parsed {
+            Date::YMD { .. } => (2, "a day that the month has"),
+            Date::Ordinal { .. } => (3, "a day that the year has"),
+            Date::Week { .. } => (1, "a day of week from 1 to 7"),
+        };
+
+        let consumed = input.offset_from(&start);
+        input.reset(&start);
+        input.next_slice(consumed.saturating_sub(digits));
+
+        fail.context(StrContext::Expected(StrContextValue::Description(expected)))
+            .context(StrContext::Label("day"))
+            .parse_next(input)
+    })
+}
+
 // UTILITY
 
 /// Exactly 1 digit
@@ -19,7 +128,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("digit_1", move |input: &mut Input| {
         take_exact_digits(input, 1)
@@ -33,7 +142,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("digit_2", move |input: &mut Input| {
         take_exact_digits(input, 2)
@@ -47,7 +156,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("digit_3", move |input: &mut Input| {
         take_exact_digits(input, 3)
@@ -61,7 +170,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("digit_4", move |input: &mut Input| {
         take_exact_digits(input, 4)
@@ -75,7 +184,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("digit_6", move |input: &mut Input| {
         take_exact_digits(input, 2)
@@ -91,7 +200,7 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     let n = take_while(places, AsChar::is_dec_digit).parse_next(input)?;
 
@@ -106,7 +215,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("take_digits", move |input: &mut Input| {
         let out = take_while(1.., AsChar::is_dec_digit).parse_next(input)?;
@@ -127,8 +236,10 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
+    let start = input.checkpoint();
+
     let out = take_while(places, AsChar::is_dec_digit).parse_next(input)?;
     let out = String::from_utf8_lossy(out.as_bstr());
     let out = u32::try_from_dec_uint(out.as_ref()).unwrap();
@@ -136,6 +247,7 @@ where
     if range.contains(&out) {
         Ok(out)
     } else {
+        input.reset(&start);
         Err(ParserError::from_input(input))
     }
 }
@@ -145,7 +257,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("sign", move |input: &mut Input| {
         let i = alt((literal("-"), literal("+")))
@@ -157,6 +269,43 @@ where
 
         Ok(i32::try_from_dec_int(i).unwrap())
     })
+    .context(StrContext::Expected(StrContextValue::CharLiteral('+')))
+    .context(StrContext::Expected(StrContextValue::CharLiteral('-')))
+    .context(StrContext::Label("sign"))
+    .parse_next(input)
+}
+
+/// Expanded year, a mandatory sign followed by exactly `4 + extra_digits` digits, such as
+/// `+002015` or `-000001` with two extra digits.
+///
+/// The number of extra digits isn't part of the value, so it has to be agreed on by both ends.
+// (+|-)YYYY[Y...]
+pub fn expanded_year<'a, Input, Error>(input: &mut Input, extra_digits: u8) -> Result<i32, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'a str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("expanded_year", move |input: &mut Input| {
+        let start = input.checkpoint();
+
+        let sign = sign(input)?;
+        let year = take_while(4 + extra_digits as usize, AsChar::is_dec_digit).parse_next(input)?;
+        let year = String::from_utf8_lossy(year.as_bstr());
+
+        match u32::try_from_dec_uint(year.as_ref()).and_then(|y| i32::try_from(y).ok()) {
+            Some(year) => Ok(sign * year),
+            None => {
+                input.reset(&start);
+                Err(ParserError::from_input(input))
+            }
+        }
+    })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "signed year with a fixed number of digits",
+    )))
+    .context(StrContext::Label("year"))
     .parse_next(input)
 }
 
@@ -168,11 +317,15 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_month", move |input: &mut Input| {
         take_digits_in_range(input, 2, 1..=12)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit month from 01 to 12",
+    )))
+    .context(StrContext::Label("month"))
     .parse_next(input)
 }
 
@@ -182,11 +335,15 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_day", move |input: &mut Input| {
         take_digits_in_range(input, 2, 1..=31)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit day from 01 to 31",
+    )))
+    .context(StrContext::Label("day"))
     .parse_next(input)
 }
 
@@ -198,11 +355,15 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_hour", move |input: &mut Input| {
         take_digits_in_range(input, 2, 0..=23)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit hour from 00 to 23",
+    )))
+    .context(StrContext::Label("hour"))
     .parse_next(input)
 }
 
@@ -212,11 +373,15 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_minute", move |input: &mut Input| {
         take_digits_in_range(input, 2, 0..=59)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit minute from 00 to 59",
+    )))
+    .context(StrContext::Label("minute"))
     .parse_next(input)
 }
 
@@ -226,24 +391,58 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_second", move |input: &mut Input| {
         take_digits_in_range(input, 2, 0..=60)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit second from 00 to 60",
+    )))
+    .context(StrContext::Label("second"))
+    .parse_next(input)
+}
+
+// Converts the fractional part of a number of seconds to nanoseconds, along with the number of
+// digits it was written with, truncating towards zero if there are more than nine digits.
+// e.g. "1" -> (100_000_000, 1), "123" -> (123_000_000, 3), "1234567891" -> (123_456_789, 10)
+pub fn fraction_nanosecond<Input, Error>(input: &mut Input) -> Result<(u32, u8), Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar,
+
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("fraction_nanosecond", move |input: &mut Input| {
+        let d = digit1(input)?;
+        let digits = d.as_bstr();
+
+        let count = u8::try_from(digits.len()).unwrap_or(u8::MAX);
+
+        let result = digits
+            .iter()
+            .chain(core::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |acc, d| acc * 10 + (d - b'0') as u32);
+
+        Ok((result, count))
+    })
+    .context(StrContext::Expected(StrContextValue::Description("digits")))
+    .context(StrContext::Label("fraction"))
     .parse_next(input)
 }
 
 // Converts the fractional part if-any of a number of seconds to milliseconds
 // truncating towards zero if there are more than three digits.
 // e.g. "" -> 0, "1" -> 100, "12" -> 120, "123" -> 123, "1234" -> 123
-pub fn fraction_millisecond<'i, Input, Error>(input: &mut Input) -> Result<u32, Error>
+pub fn fraction_millisecond<Input, Error>(input: &mut Input) -> Result<u32, Error>
 where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar,
 
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("fraction_millisecond", move |input: &mut Input| {
         let d = digit1(input)?;
@@ -268,5 +467,7 @@ where
 
         Ok(result)
     })
+    .context(StrContext::Expected(StrContextValue::Description("digits")))
+    .context(StrContext::Label("fraction"))
     .parse_next(input)
 }
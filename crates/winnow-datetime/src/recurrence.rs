@@ -0,0 +1,364 @@
+//! Expanding a recurring interval such as `R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M` into the
+//! start and end of each of its occurrences.
+//!
+//! Occurrences are counted from the start of the interval, or back from its end when it is given
+//! as a duration and an end, so that `R/P1D/2015-06-26` is every day up to the 26th of June. The
+//! `n`th occurrence is `n` times the duration away from that anchor rather than one duration away
+//! from the previous occurrence, so that monthly occurrences from the 31st come back to the 31st
+//! after a short month, and each one ends where the next one starts.
+
+use crate::arithmetic::DurationUnit;
+use crate::instant::datetime_key;
+use crate::types::{Fraction, IntervalRange};
+use crate::{DateTime, Duration, Interval, InvalidInterval};
+use core::iter::FusedIterator;
+
+// the duration `times` times over, carrying whole seconds out of the fraction
+fn scale(duration: &Duration, times: u32) -> Option<Duration> {
+    let fraction = duration
+        .fraction
+        .map_or(0, |fraction| fraction.nanos as u64 * times as u64);
+
+    Some(Duration {
+        negative: duration.negative,
+        years: duration.years.checked_mul(times)?,
+        months: duration.months.checked_mul(times)?,
+        weeks: duration.weeks.checked_mul(times)?,
+        days: duration.days.checked_mul(times)?,
+        hours: duration.hours.checked_mul(times)?,
+        minutes: duration.minutes.checked_mul(times)?,
+        seconds: duration
+            .seconds
+            .checked_mul(times)?
+            .checked_add(u32::try_from(fraction / 1_000_000_000).ok()?)?,
+        fraction: duration
+            .fraction
+            .map(|f| Fraction::new((fraction % 1_000_000_000) as u32, f.digits)),
+    })
+}
+
+/// The start and end of each occurrence of a recurring interval, from [`Interval::occurrences`].
+///
+/// Iteration ends after the number of repetitions of the interval, never for an unbounded one
+/// unless [`Occurrences::cut_off`] is used, or when an occurrence is out of range.
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    anchor: DateTime,
+    duration: Duration,
+    backwards: bool,
+    index: u32,
+    remaining: Option<u32>,
+    cut_off: Option<DateTime>,
+}
+
+impl Occurrences {
+    /// Stops before the first occurrence starting after `cut_off`, or ending before it when the
+    /// occurrences are counted back from the end of the interval. Datetimes without an offset are
+    /// compared as if they were in UTC.
+    pub fn cut_off(self, cut_off: &DateTime) -> Occurrences {
+        Occurrences {
+            cut_off: Some(cut_off.clone()),
+            ..self
+        }
+    }
+
+    fn occurrence(&self) -> Option<(DateTime, DateTime)> {
+        let near = scale(&self.duration, self.index)?;
+        let far = scale(&self.duration, self.index.checked_add(1)?)?;
+
+        let (start, end) = if self.backwards {
+            (
+                self.anchor.checked_sub(&far)?,
+                self.anchor.checked_sub(&near)?,
+            )
+        } else {
+            (
+                self.anchor.checked_add(&near)?,
+                self.anchor.checked_add(&far)?,
+            )
+        };
+
+        match &self.cut_off {
+            Some(cut_off) if self.backwards && datetime_key(&end) < datetime_key(cut_off) => None,
+            Some(cut_off) if !self.backwards && datetime_key(&start) > datetime_key(cut_off) => {
+                None
+            }
+            _ => Some((start, end)),
+        }
+    }
+}
+
+impl Iterator for Occurrences {
+    type Item = (DateTime, DateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        match self.occurrence() {
+            Some(occurrence) => {
+                self.remaining = match self.index.checked_add(1) {
+                    Some(index) => {
+                        self.index = index;
+                        self.remaining.map(|remaining| remaining - 1)
+                    }
+                    None => Some(0),
+                };
+
+                Some(occurrence)
+            }
+            None => {
+                self.remaining = Some(0);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.remaining.map(|remaining| remaining as usize))
+    }
+}
+
+impl FusedIterator for Occurrences {}
+
+impl Interval {
+    /// The start and end of each occurrence of the interval, as many as its repetitions say: one
+    /// without `R`, `n` for `Rn` and without end for `R`.
+    ///
+    /// Occurrences are a duration apart, the duration between the start and end of the interval
+    /// when it has both, in which case they have the offset of the start. They are counted back
+    /// from the end, latest first, when the interval is a duration and an end. Fails as
+    /// [`Interval::resolve`] does.
+    ///
+    /// ```
+    /// use winnow_datetime::types::{Interval, IntervalRange, PartialDate, PartialDateTime};
+    /// use winnow_datetime::{Date, DateTime, Duration};
+    ///
+    /// let start = PartialDateTime {
+    ///     date: Some(PartialDate::YMD { year: Some(2015), month: Some(1), day: Some(31) }),
+    ///     time: None,
+    /// };
+    /// let interval = Interval {
+    ///     repetitions: Some(None),
+    ///     range: IntervalRange::ClosedStart { start, duration: Duration::from_ymd(0, 1, 0) },
+    /// };
+    ///
+    /// let cut_off = DateTime {
+    ///     date: Date::from_ymd(2015, 4, 1).unwrap(),
+    ///     ..Default::default()
+    /// };
+    /// let starts: Vec<_> = interval
+    ///     .occurrences()
+    ///     .unwrap()
+    ///     .cut_off(&cut_off)
+    ///     .map(|(start, _)| start.date.to_string())
+    ///     .collect();
+    ///
+    /// assert_eq!(starts, ["2015-01-31", "2015-02-28", "2015-03-31"]);
+    /// ```
+    pub fn occurrences(&self) -> Result<Occurrences, InvalidInterval> {
+        let (start, end) = self.range.resolve()?;
+
+        let (anchor, duration, backwards) = match self.range {
+            IntervalRange::Closed { .. } => {
+                let duration = start
+                    .until(&end, DurationUnit::Years)
+                    .ok_or(InvalidInterval::OutOfRange)?;
+                (start, duration, false)
+            }
+            IntervalRange::ClosedStart { duration, .. } => (start, duration, false),
+            IntervalRange::ClosedEnd { duration, .. } => (end, duration, true),
+            IntervalRange::Open { .. } => return Err(InvalidInterval::Unanchored),
+        };
+
+        Ok(Occurrences {
+            anchor,
+            duration,
+            backwards,
+            index: 0,
+            remaining: match self.repetitions {
+                None => Some(1),
+                Some(repetitions) => repetitions,
+            },
+            cut_off: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PartialDate, PartialDateTime};
+    use crate::Date;
+    use alloc::vec::Vec;
+
+    fn day(year: i32, month: u32, day: u32) -> PartialDateTime {
+        PartialDateTime {
+            date: Some(PartialDate::YMD {
+                year: Some(year),
+                month: Some(month),
+                day: Some(day),
+            }),
+            time: None,
+        }
+    }
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime {
+        DateTime {
+            date: Date::from_ymd(year, month, day).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn bounded_occurrences() {
+        let interval = Interval {
+            repetitions: Some(Some(3)),
+            range: IntervalRange::Closed {
+                start: day(2015, 6, 25),
+                end: day(2015, 6, 27),
+            },
+        };
+
+        let occurrences: Vec<_> = interval.occurrences().unwrap().collect();
+        assert_eq!(
+            occurrences,
+            [
+                (at(2015, 6, 25), at(2015, 6, 27)),
+                (at(2015, 6, 27), at(2015, 6, 29)),
+                (at(2015, 6, 29), at(2015, 7, 1)),
+            ]
+        );
+
+        let once = Interval {
+            repetitions: None,
+            ..interval
+        };
+        assert_eq!(once.occurrences().unwrap().count(), 1);
+
+        let never = Interval {
+            repetitions: Some(Some(0)),
+            ..interval
+        };
+        assert_eq!(never.occurrences().unwrap().next(), None);
+    }
+
+    #[test]
+    fn occurrences_back_from_the_end() {
+        let interval = Interval {
+            repetitions: Some(None),
+            range: IntervalRange::ClosedEnd {
+                duration: Duration::from_ymd(1, 0, 0),
+                end: day(2016, 2, 29),
+            },
+        };
+
+        let occurrences: Vec<_> = interval
+            .occurrences()
+            .unwrap()
+            .cut_off(&at(2014, 1, 1))
+            .collect();
+        assert_eq!(
+            occurrences,
+            [
+                (at(2015, 2, 28), at(2016, 2, 29)),
+                (at(2014, 2, 28), at(2015, 2, 28)),
+                (at(2013, 2, 28), at(2014, 2, 28)),
+            ]
+        );
+    }
+
+    #[test]
+    fn occurrences_end_where_the_next_start() {
+        let monthly = |range| Interval {
+            repetitions: Some(Some(3)),
+            range,
+        };
+
+        let forwards: Vec<_> = monthly(IntervalRange::ClosedStart {
+            start: day(2015, 1, 31),
+            duration: Duration::from_ymd(0, 1, 0),
+        })
+        .occurrences()
+        .unwrap()
+        .collect();
+        assert_eq!(
+            forwards,
+            [
+                (at(2015, 1, 31), at(2015, 2, 28)),
+                (at(2015, 2, 28), at(2015, 3, 31)),
+                (at(2015, 3, 31), at(2015, 4, 30)),
+            ]
+        );
+
+        let backwards: Vec<_> = monthly(IntervalRange::ClosedEnd {
+            duration: Duration::from_ymd(0, 1, 0),
+            end: day(2015, 3, 31),
+        })
+        .occurrences()
+        .unwrap()
+        .collect();
+        assert_eq!(
+            backwards,
+            [
+                (at(2015, 2, 28), at(2015, 3, 31)),
+                (at(2015, 1, 31), at(2015, 2, 28)),
+                (at(2014, 12, 31), at(2015, 1, 31)),
+            ]
+        );
+
+        for occurrences in [forwards, backwards.into_iter().rev().collect()] {
+            for pair in occurrences.windows(2) {
+                assert_eq!(pair[0].1, pair[1].0);
+            }
+        }
+    }
+
+    #[test]
+    fn occurrences_with_fractions() {
+        let interval = Interval {
+            repetitions: Some(Some(4)),
+            range: IntervalRange::ClosedStart {
+                start: day(2015, 6, 25),
+                duration: Duration {
+                    fraction: Some(Fraction::new(400_000_000, 1)),
+                    ..Default::default()
+                },
+            },
+        };
+
+        let starts: Vec<_> = interval
+            .occurrences()
+            .unwrap()
+            .map(|(start, _)| (start.time.second, start.time.nanosecond))
+            .collect();
+        assert_eq!(
+            starts,
+            [(0, 0), (0, 400_000_000), (0, 800_000_000), (1, 200_000_000)]
+        );
+    }
+
+    #[test]
+    fn occurrences_out_of_range() {
+        let interval = Interval {
+            repetitions: Some(None),
+            range: IntervalRange::ClosedStart {
+                start: day(i32::MAX - 1, 1, 1),
+                duration: Duration::from_ymd(1, 0, 0),
+            },
+        };
+
+        let mut occurrences = interval.occurrences().unwrap();
+        assert_eq!(occurrences.by_ref().count(), 1);
+        assert_eq!(occurrences.next(), None);
+
+        let open = Interval {
+            repetitions: Some(None),
+            range: IntervalRange::Open {
+                duration: Duration::from_ymd(1, 0, 0),
+            },
+        };
+        assert_eq!(open.occurrences().err(), Some(InvalidInterval::Unanchored));
+    }
+}
@@ -0,0 +1,131 @@
+//! Finding values such as timestamps in free text, like a log line or a message.
+//!
+//! [`Matches`] tries a parser at every position of the text that starts a word, and keeps what it
+//! parses when it also ends one, so that `x2015-06-26T16:43:23Z` or `2015-06-26T16:43:23Z_1`
+//! aren't matched in the middle of a token. As with `\b` in a regex, letters, digits and `_` make
+//! up words, and a match can only start or end next to anything else.
+//!
+//! A match followed by `:`, `.`, `,`, `+` or `-` and then more of the word is dropped as well, as
+//! it is only the start of a longer value that didn't parse, such as `2015-06-26T16:43` out of
+//! `2015-06-26T16:43:99`. Punctuation ending a sentence, as in `at 2015-06-26T16:43:23Z.`, is
+//! fine. The format crates each provide a `find_datetimes` function built on it.
+
+use core::ops::Range;
+use winnow::error::ContextError;
+
+/// A value found in the text, with the byte span it was parsed from.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Match<O> {
+    /// the parsed value
+    pub value: O,
+    /// byte offset of the start of the match
+    pub start: usize,
+    /// byte offset just past the end of the match
+    pub end: usize,
+}
+
+impl<O> Match<O> {
+    /// The byte span of the match, `&text[m.range()]` being the text it was parsed from
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+// whether a match can start or end next to this character, anything but a letter, a digit or `_`
+fn is_boundary(c: Option<char>) -> bool {
+    !c.is_some_and(|c| c.is_alphanumeric() || c == '_')
+}
+
+// whether the text after a match goes on with more of the value, a separator then a word
+fn continues(rest: &str) -> bool {
+    let mut chars = rest.chars();
+
+    matches!(chars.next(), Some(':' | '.' | ',' | '+' | '-')) && !is_boundary(chars.next())
+}
+
+/// Iterator over the values a parser finds in a text, see the [module docs](self).
+///
+/// Matches don't overlap: once a value is found the search goes on from its end.
+///
+/// ```
+/// use winnow_datetime::parser::digit_4;
+/// use winnow_datetime::scan::Matches;
+///
+/// let years: Vec<_> = Matches::new("1999, 2015 and x2016", digit_4)
+///     .map(|m| (m.value, m.range()))
+///     .collect();
+///
+/// assert_eq!(years, [(1999, 0..4), (2015, 6..10)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Matches<'i, O> {
+    text: &'i str,
+    position: usize,
+    parser: fn(&mut &'i str) -> Result<O, ContextError>,
+}
+
+impl<'i, O> Matches<'i, O> {
+    /// Searches `text` for what `parser` accepts
+    pub fn new(text: &'i str, parser: fn(&mut &'i str) -> Result<O, ContextError>) -> Self {
+        Matches {
+            text,
+            position: 0,
+            parser,
+        }
+    }
+}
+
+impl<O> Iterator for Matches<'_, O> {
+    type Item = Match<O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position < self.text.len() {
+            let start = self.position;
+            let mut rest = &self.text[start..];
+            self.position += rest.chars().next().map_or(1, char::len_utf8);
+
+            if !is_boundary(self.text[..start].chars().next_back()) {
+                continue;
+            }
+
+            if let Ok(value) = (self.parser)(&mut rest) {
+                let end = self.text.len() - rest.len();
+
+                if end > start && is_boundary(rest.chars().next()) && !continues(rest) {
+                    self.position = end;
+
+                    return Some(Match { value, start, end });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::digit_4;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn word_boundaries() {
+        let spans = |text| {
+            Matches::new(text, digit_4::<_, ContextError>)
+                .map(|m| (m.start, m.end))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(spans("2015"), [(0, 4)]);
+        assert_eq!(spans("(2015)-[2016]"), [(1, 5), (8, 12)]);
+        assert_eq!(spans("x2015 2015x 20155 é2015 2015é"), []);
+        assert_eq!(spans("↪2015 20152016"), [(3, 7)]);
+        assert_eq!(spans("log_2015 2015_01 2015-"), [(17, 21)]);
+        assert_eq!(spans("2015-01 2015:1 2015.5 2015,0 2015+1"), []);
+        assert_eq!(
+            spans("2015. 2015, 2015: 2015-"),
+            [(0, 4), (6, 10), (12, 16), (18, 22)]
+        );
+    }
+}
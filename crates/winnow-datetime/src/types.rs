@@ -1,3 +1,5 @@
+use crate::gregorian::{self, InvalidDate};
+use alloc::string::String;
 use core::fmt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +13,7 @@ use serde::{Deserialize, Serialize};
 ///     winnow_datetime::DateTime::from_str("2023-02-18T17:08:08.793Z"),
 ///     Ok(winnow_datetime::DateTime {
 ///         date: winnow_datetime::Date::YMD{ year: 2023, month: 2, day: 18},
-///         time: winnow_datetime::Time{ hour: 17, minute: 8, second: 8, millisecond: 793, offset: Offset { offset_hours: 0, offset_minutes: 00 }}
+///         time: winnow_datetime::Time{ hour: 17, minute: 8, second: 8, nanosecond: 793_000_000, fraction_digits: 3, offset: Offset { offset_hours: 0, offset_minutes: 00 }}
 ///     })
 /// )
 /// */
@@ -25,6 +27,40 @@ pub struct DateTime {
     pub time: Time,
 }
 
+impl DateTime {
+    /// Applies a [`LeapSecond`] policy to a time with a second of 60, moving to the next day
+    /// when rolling over `23:59:60`. Other datetimes are returned unchanged.
+    ///
+    /// ```
+    /// use winnow_datetime::{Date, DateTime, LeapSecond, Time};
+    ///
+    /// let datetime = DateTime {
+    ///     date: Date::from_ymd(2016, 12, 31).unwrap(),
+    ///     time: Time::from_hms(23, 59, 60).unwrap(),
+    /// };
+    ///
+    /// let rolled = datetime.clone().with_leap_second(LeapSecond::RollOver).unwrap();
+    /// assert_eq!(rolled.to_string(), "2017-01-01T00:00:00");
+    ///
+    /// let clamped = datetime.clone().with_leap_second(LeapSecond::Clamp).unwrap();
+    /// assert_eq!(clamped.to_string(), "2016-12-31T23:59:59.999999999");
+    ///
+    /// assert!(datetime.with_leap_second(LeapSecond::Reject).is_err());
+    /// ```
+    pub fn with_leap_second(self, policy: LeapSecond) -> Result<DateTime, InvalidTime> {
+        let hour = self.time.hour;
+        let time = self.time.with_leap_second(policy)?;
+
+        let date = if time.hour < hour {
+            self.date.add_days(1).ok_or(InvalidTime::Second(60))?
+        } else {
+            self.date
+        };
+
+        Ok(DateTime { date, time })
+    }
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
 pub struct PartialDateTime {
@@ -45,8 +81,12 @@ pub trait OffsetFormat: Sized {
 }
 
 /// A date object.
+///
+/// Dates compare equal when they are the same calendar day, whatever their representation, so
+/// `2023-W06-3` is equal to `2023-02-08`. A date that doesn't exist, such as `2023-02-30`, is only
+/// equal to itself written the same way.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone)]
 pub enum Date {
     /// consists of year, month and day of month
     YMD { year: i32, month: u32, day: u32 },
@@ -60,10 +100,285 @@ impl Default for Date {
     fn default() -> Date {
         Date::YMD {
             year: 0,
-            month: 0,
-            day: 0,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl PartialEq for Date {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Date {}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Dates are ordered by calendar day, whatever their representation. A date that doesn't exist
+/// comes after the dates that do on the day it would be, and dates that don't exist on the same
+/// day are ordered as written.
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Date {
+    /// Creates a calendar date, failing if it doesn't exist.
+    ///
+    /// ```
+    /// use winnow_datetime::gregorian::InvalidDate;
+    /// use winnow_datetime::Date;
+    ///
+    /// assert!(Date::from_ymd(2016, 2, 29).is_ok());
+    /// assert_eq!(Date::from_ymd(2015, 2, 29), Err(InvalidDate::Day(29)));
+    /// ```
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Date, InvalidDate> {
+        let date = Date::YMD { year, month, day };
+        date.validate()?;
+        Ok(date)
+    }
+
+    /// Creates an ISO week date, `weekday` going from 1 for Monday to 7 for Sunday, failing if it
+    /// doesn't exist.
+    pub fn from_iso_week(year: i32, week: u32, weekday: u32) -> Result<Date, InvalidDate> {
+        let date = Date::Week {
+            year,
+            week,
+            day: weekday,
+        };
+        date.validate()?;
+        Ok(date)
+    }
+
+    /// Creates an ordinal date from the day of the year, failing if it doesn't exist.
+    pub fn from_ordinal(year: i32, day: u32) -> Result<Date, InvalidDate> {
+        let date = Date::Ordinal { year, day };
+        date.validate()?;
+        Ok(date)
+    }
+
+    /// The calendar year. For week dates this can differ from the ISO week-numbering year around
+    /// new year, see [`Date::iso_week`].
+    ///
+    /// The accessors work on every variant but are only meaningful for dates that exist, see
+    /// [`Date::validate`].
+    pub fn year(&self) -> i32 {
+        self.year_ordinal().0
+    }
+
+    /// The month, from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.year_month_day().1
+    }
+
+    /// The day of the month, from 1 to 31.
+    pub fn day(&self) -> u32 {
+        self.year_month_day().2
+    }
+
+    /// The day of the year, from 1 to 366.
+    pub fn ordinal(&self) -> u32 {
+        self.year_ordinal().1
+    }
+
+    /// The day of the week, from 1 for Monday to 7 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        match *self {
+            Date::Week { day, .. } => day,
+            _ => {
+                let (year, ordinal) = self.year_ordinal();
+                gregorian::weekday(year, ordinal)
+            }
+        }
+    }
+
+    /// The ISO week-numbering year and the week within it.
+    ///
+    /// ```
+    /// use winnow_datetime::Date;
+    ///
+    /// assert_eq!(Date::from_ymd(2021, 1, 1).unwrap().iso_week(), (2020, 53));
+    /// ```
+    pub fn iso_week(&self) -> (i32, u32) {
+        if let Date::Week { year, week, .. } = *self {
+            return (year, week);
+        }
+
+        let (year, ordinal) = self.year_ordinal();
+        let week = (ordinal as i64 - gregorian::weekday(year, ordinal) as i64 + 10) / 7;
+
+        if week < 1 {
+            (year - 1, gregorian::weeks_in_year(year - 1))
+        } else if week > gregorian::weeks_in_year(year) as i64 {
+            (year + 1, 1)
+        } else {
+            (year, week as u32)
+        }
+    }
+
+    /// The same day as a calendar date, `2023-02-08`.
+    ///
+    /// ```
+    /// use winnow_datetime::Date;
+    ///
+    /// let date = Date::from_iso_week(2023, 6, 3).unwrap().to_ymd();
+    /// assert!(matches!(date, Date::YMD { year: 2023, month: 2, day: 8 }));
+    /// ```
+    pub fn to_ymd(&self) -> Date {
+        let (year, month, day) = self.year_month_day();
+
+        Date::YMD { year, month, day }
+    }
+
+    /// The same day as an ISO week date, `2023-W06-3`.
+    pub fn to_iso_week(&self) -> Date {
+        let (year, week) = self.iso_week();
+
+        Date::Week {
+            year,
+            week,
+            day: self.weekday(),
+        }
+    }
+
+    /// The same day as an ordinal date, `2023-039`.
+    pub fn to_ordinal(&self) -> Date {
+        let (year, day) = self.year_ordinal();
+
+        Date::Ordinal { year, day }
+    }
+
+    // the day `days` after this one, in the same representation, or `None` if the year doesn't
+    // fit an `i32`
+    pub(crate) fn add_days(&self, days: i64) -> Option<Date> {
+        let (year, ordinal) = self.year_ordinal();
+        let (year, day) = gregorian::from_days_since_epoch(
+            gregorian::days_since_epoch(year, ordinal).checked_add(days)?,
+        )?;
+
+        Some(Date::Ordinal { year, day }.to_representation_of(self))
+    }
+
+    // the same day in the representation of `like`
+    pub(crate) fn to_representation_of(self, like: &Date) -> Date {
+        match like {
+            Date::YMD { .. } => self.to_ymd(),
+            Date::Week { .. } => self.to_iso_week(),
+            Date::Ordinal { .. } => self.to_ordinal(),
         }
     }
+
+    // the calendar day of a date that exists, and for one that doesn't the date as written, so
+    // that equality is transitive and agrees with the ordering
+    fn key(&self) -> (i64, i64, Option<[i64; 4]>) {
+        let written = match *self {
+            _ if self.validate().is_ok() => None,
+            Date::YMD { year, month, day } => Some([0, year as i64, month as i64, day as i64]),
+            Date::Week { year, week, day } => Some([1, year as i64, week as i64, day as i64]),
+            Date::Ordinal { year, day } => Some([2, year as i64, 0, day as i64]),
+        };
+        let (year, ordinal) = self.wide_year_ordinal();
+
+        (year, ordinal, written)
+    }
+
+    // the year and day of the year, wide enough for the days of the first week of `i32::MIN` in
+    // the year before it, or of the last week of `i32::MAX` in the year after, and for days past
+    // the end of the month
+    fn wide_year_ordinal(&self) -> (i64, i64) {
+        match *self {
+            Date::YMD { year, month, day } => (
+                year as i64,
+                // a month past December counts from the end of the year
+                gregorian::days_before_month(year, month).unwrap_or(match month {
+                    0 => 0,
+                    _ => gregorian::days_in_year(year),
+                }) as i64
+                    + day as i64,
+            ),
+            Date::Ordinal { year, day } => (year as i64, day as i64),
+            Date::Week { year, week, day } => {
+                // the 4th of January is always in the first week
+                let ordinal =
+                    week as i64 * 7 + day as i64 - (gregorian::weekday(year, 4) as i64 + 3);
+                let year = year as i64;
+
+                if ordinal < 1 {
+                    (year - 1, ordinal + gregorian::days_in_wide_year(year - 1))
+                } else if ordinal > gregorian::days_in_wide_year(year) {
+                    (year + 1, ordinal - gregorian::days_in_wide_year(year))
+                } else {
+                    (year, ordinal)
+                }
+            }
+        }
+    }
+
+    fn year_ordinal(&self) -> (i32, u32) {
+        let (year, ordinal) = self.wide_year_ordinal();
+
+        // the days of a week date outside the range of an `i32` stay in its first or last year
+        (
+            year.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            ordinal as u32,
+        )
+    }
+
+    fn year_month_day(&self) -> (i32, u32, u32) {
+        if let Date::YMD { year, month, day } = *self {
+            return (year, month, day);
+        }
+
+        let (year, ordinal) = self.year_ordinal();
+        let month = (1..=12)
+            .rev()
+            .find(|month| gregorian::days_before_month(year, *month).unwrap_or(0) < ordinal)
+            .unwrap_or(1);
+
+        (
+            year,
+            month,
+            ordinal - gregorian::days_before_month(year, month).unwrap_or(0),
+        )
+    }
+
+    /// Checks that the date exists in the proleptic Gregorian calendar, taking month lengths, leap
+    /// years and years with 53 ISO weeks into account.
+    pub fn validate(&self) -> Result<(), InvalidDate> {
+        match *self {
+            Date::YMD { year, month, day } => {
+                let days =
+                    gregorian::days_in_month(year, month).ok_or(InvalidDate::Month(month))?;
+
+                if day == 0 || day > days {
+                    return Err(InvalidDate::Day(day));
+                }
+            }
+            Date::Week { year, week, day } => {
+                if week == 0 || week > gregorian::weeks_in_year(year) {
+                    return Err(InvalidDate::Week(week));
+                }
+
+                if !(1..=7).contains(&day) {
+                    return Err(InvalidDate::Weekday(day));
+                }
+            }
+            Date::Ordinal { year, day } => {
+                if day == 0 || day > gregorian::days_in_year(year) {
+                    return Err(InvalidDate::Day(day));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -97,8 +412,10 @@ pub struct Time {
     pub minute: u32,
     /// a minute are 60 of these
     pub second: u32,
-    /// everything after a `.`
-    pub millisecond: u32,
+    /// everything after a `.`, in nanoseconds
+    pub nanosecond: u32,
+    /// number of digits the fraction was written with, `0` when there was none
+    pub fraction_digits: u8,
     /// Note, offset can't be partial, so a regular Offset is used
     pub offset: Option<Offset>,
     /// time zone, which is more reliable than offset
@@ -107,13 +424,198 @@ pub struct Time {
     pub calendar: Option<Calendar>,
 }
 
+impl Time {
+    /// Creates a time without a fraction or an offset.
+    ///
+    /// The second can be 60 to represent a leap second.
+    pub fn from_hms(hour: u32, minute: u32, second: u32) -> Result<Time, InvalidTime> {
+        Time::from_hms_nano(hour, minute, second, 0)
+    }
+
+    /// Creates a time with a fraction of the second in milliseconds, written with three digits.
+    pub fn from_hms_milli(
+        hour: u32,
+        minute: u32,
+        second: u32,
+        millisecond: u32,
+    ) -> Result<Time, InvalidTime> {
+        if millisecond >= 1_000 {
+            return Err(InvalidTime::Millisecond(millisecond));
+        }
+
+        Ok(Time {
+            fraction_digits: 3,
+            ..Time::from_hms_nano(hour, minute, second, millisecond * 1_000_000)?
+        })
+    }
+
+    /// Creates a time with a fraction of the second in nanoseconds.
+    pub fn from_hms_nano(
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanosecond: u32,
+    ) -> Result<Time, InvalidTime> {
+        if hour > 23 {
+            return Err(InvalidTime::Hour(hour));
+        }
+
+        if minute > 59 {
+            return Err(InvalidTime::Minute(minute));
+        }
+
+        if second > 60 {
+            return Err(InvalidTime::Second(second));
+        }
+
+        if nanosecond >= 1_000_000_000 {
+            return Err(InvalidTime::Nanosecond(nanosecond));
+        }
+
+        Ok(Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            ..Default::default()
+        })
+    }
+
+    /// The fraction of the second truncated to milliseconds.
+    pub fn millisecond(&self) -> u32 {
+        self.nanosecond / 1_000_000
+    }
+
+    /// Applies a [`LeapSecond`] policy to a time with a second of 60, other times are returned
+    /// unchanged.
+    ///
+    /// Rolling over `23:59:60` wraps around to `00:00:00`, use [`DateTime::with_leap_second`] to
+    /// move to the next day as well.
+    pub fn with_leap_second(self, policy: LeapSecond) -> Result<Time, InvalidTime> {
+        if self.second != 60 {
+            return Ok(self);
+        }
+
+        match policy {
+            LeapSecond::Reject => Err(InvalidTime::Second(60)),
+            LeapSecond::Clamp => Ok(Time {
+                second: 59,
+                nanosecond: 999_999_999,
+                fraction_digits: 9,
+                ..self
+            }),
+            LeapSecond::RollOver => {
+                let minutes = (self.hour * 60 + self.minute + 1) % (24 * 60);
+
+                Ok(Time {
+                    hour: minutes / 60,
+                    minute: minutes % 60,
+                    second: 0,
+                    ..self
+                })
+            }
+        }
+    }
+}
+
+/// What to do with a leap second, a time with a second of 60 such as `23:59:60Z`.
+///
+/// The parsers accept leap seconds as RFC 3339 and ISO 8601 allow them. The chrono conversions
+/// keep them, while time and jiff can't represent them and their conversions apply the default.
+/// The `into_*_with` conversions of all three take the policy to apply, and
+/// [`DateTime::with_leap_second`] and [`Time::with_leap_second`] apply one without converting.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum LeapSecond {
+    /// fail with [`InvalidTime::Second`]
+    Reject,
+    /// use the last nanosecond of the previous second, `23:59:59.999999999`
+    #[default]
+    Clamp,
+    /// use the first second of the next minute, `00:00:00` of the next day
+    RollOver,
+}
+
+/// Reasons a [`Time`], an [`Offset`] or a [`DateTime`] from a Unix timestamp can't be created.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum InvalidTime {
+    /// the hour isn't between 0 and 23
+    Hour(u32),
+    /// the minute isn't between 0 and 59
+    Minute(u32),
+    /// the second isn't between 0 and 60
+    Second(u32),
+    /// the millisecond isn't between 0 and 999
+    Millisecond(u32),
+    /// the nanosecond isn't between 0 and 999 999 999
+    Nanosecond(u32),
+    /// the offset, in seconds, isn't a whole number of minutes of less than a day
+    Offset(i32),
+    /// the Unix timestamp, in seconds, is outside of the years that fit an `i32`
+    Timestamp(i64),
+}
+
+impl fmt::Display for InvalidTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidTime::Hour(hour) => write!(f, "hour {} doesn't exist", hour),
+            InvalidTime::Minute(minute) => write!(f, "minute {} doesn't exist", minute),
+            InvalidTime::Second(second) => write!(f, "second {} doesn't exist", second),
+            InvalidTime::Millisecond(millisecond) => {
+                write!(f, "millisecond {} doesn't exist", millisecond)
+            }
+            InvalidTime::Nanosecond(nanosecond) => {
+                write!(f, "nanosecond {} doesn't exist", nanosecond)
+            }
+            InvalidTime::Offset(seconds) => write!(f, "offset of {} seconds isn't valid", seconds),
+            InvalidTime::Timestamp(seconds) => {
+                write!(f, "timestamp of {} seconds is out of range", seconds)
+            }
+        }
+    }
+}
+
+impl core::error::Error for InvalidTime {}
+
+/// Reasons an [`Interval`] can't be resolved into a start and an end.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum InvalidInterval {
+    /// a start or end doesn't have a full date, or has a time without an hour
+    Incomplete,
+    /// a start or end date doesn't exist in the calendar
+    Date(InvalidDate),
+    /// a start or end time doesn't exist
+    Time(InvalidTime),
+    /// the interval is only a duration, with nothing to count it from
+    Unanchored,
+    /// adding or subtracting the duration goes past the years that fit an `i32`
+    OutOfRange,
+}
+
+impl fmt::Display for InvalidInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidInterval::Incomplete => write!(f, "interval doesn't give a full date and time"),
+            InvalidInterval::Date(err) => write!(f, "interval date is invalid: {}", err),
+            InvalidInterval::Time(err) => write!(f, "interval time is invalid: {}", err),
+            InvalidInterval::Unanchored => write!(f, "interval has only a duration"),
+            InvalidInterval::OutOfRange => write!(f, "interval is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for InvalidInterval {}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub struct PartialTime {
     pub hour: Option<u32>,
     pub minute: Option<u32>,
     pub second: Option<u32>,
-    pub millisecond: Option<u32>,
+    /// everything after a `.`, in nanoseconds
+    pub nanosecond: Option<u32>,
+    /// number of digits the fraction was written with, `0` when there was none
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub fraction_digits: u8,
     pub offset: Option<Offset>,
 }
 
@@ -165,29 +667,74 @@ pub enum Offset {
     },
 }
 
+impl Offset {
+    /// UTC, written as `Z` or `+00:00`.
+    pub const UTC: Offset = Offset::Fixed {
+        hours: 0,
+        minutes: 0,
+        critical: false,
+    };
+
+    /// Creates a fixed offset from UTC, failing unless it is a whole number of minutes of less
+    /// than a day.
+    ///
+    /// ```
+    /// use winnow_datetime::Offset;
+    ///
+    /// assert_eq!(
+    ///     Offset::from_seconds(-5 * 3600 - 30 * 60),
+    ///     Ok(Offset::Fixed { hours: -5, minutes: -30, critical: false })
+    /// );
+    /// assert!(Offset::from_seconds(30).is_err());
+    /// ```
+    pub fn from_seconds(seconds: i32) -> Result<Offset, InvalidTime> {
+        if seconds % 60 != 0 || seconds.unsigned_abs() >= 86_400 {
+            return Err(InvalidTime::Offset(seconds));
+        }
+
+        Ok(Offset::Fixed {
+            hours: seconds / 3600,
+            minutes: seconds % 3600 / 60,
+            critical: false,
+        })
+    }
+
+    /// The offset from UTC in seconds, or `None` if the local offset is unknown.
+    pub fn seconds(&self) -> Option<i32> {
+        match *self {
+            Offset::LocalUnknown { .. } => None,
+            Offset::Fixed { hours, minutes, .. } => Some(hours * 3600 + minutes * 60),
+        }
+    }
+}
+
 /// A time duration.
 ///
 /// ## Duration Grammar
 ///
-/// | Duration     | ABNF Description                                     |
-/// | ------------ | ---------------------------------------------------- |
-/// | `dur-second` | 1*DIGIT "S"                                          |
-/// | `dur-minute` | 1*DIGIT "M" [`dur-second`]                           |
-/// | `dur-hour`   | 1*DIGIT "H" [`dur-minute`]                           |
-/// | `dur-time`   | "T" (`dur-hour` / `dur-minute` / `dur-second`)       |
-/// | `dur-day`    | 1*DIGIT "D"                                          |
-/// | `dur-week`   | 1*DIGIT "W"                                          |
-/// | `dur-month`  | 1*DIGIT "M" [`dur-day`]                              |
-/// | `dur-year`   | 1*DIGIT "Y" [`dur-month`]                            |
-/// | `dur-date`   | (`dur-day` / `dur-month` / `dur-year`) [`dur-time`]  |
-/// | `duration`   | "P" (`dur-date` / `dur-time` / `dur-week`)           |
+/// | Duration     | ABNF Description                                       |
+/// | ------------ | ------------------------------------------------------ |
+/// | `dur-second` | 1*DIGIT "S"                                            |
+/// | `dur-minute` | 1*DIGIT "M" [`dur-second`]                             |
+/// | `dur-hour`   | 1*DIGIT "H" [`dur-minute`]                             |
+/// | `dur-time`   | "T" (`dur-hour` / `dur-minute` / `dur-second`)         |
+/// | `dur-day`    | 1*DIGIT "D"                                            |
+/// | `dur-week`   | 1*DIGIT "W"                                            |
+/// | `dur-month`  | 1*DIGIT "M" [`dur-day`]                                |
+/// | `dur-year`   | 1*DIGIT "Y" [`dur-month`]                              |
+/// | `dur-date`   | (`dur-day` / `dur-month` / `dur-year`) [`dur-time`]    |
+/// | `duration`   | ["+" / "-"] "P" (`dur-date` / `dur-time` / `dur-week`) |
+///
+/// A leading `-`, allowed by Temporal and some ISO 8601 profiles, makes the whole duration
+/// negative.
 ///
 /// ## Examples
 /// ```
 /// // 1 year, 11 months, 16 days, 23 hours, 26 minutes, 59 seconds, 123 milliseconds
-/// use winnow_datetime::types::Duration;
+/// use winnow_datetime::types::{Duration, Fraction};
 ///
 /// let d = Duration {
+///      negative: false,
 ///      years: 1,
 ///      months: 11,
 ///      weeks: 0,
@@ -195,12 +742,14 @@ pub enum Offset {
 ///      hours: 23,
 ///      minutes: 26,
 ///      seconds: 59,
-///      milliseconds: Some(0.123)
+///      fraction: Some(Fraction::new(123_000_000, 3))
 /// };
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Eq, PartialEq, Hash, Debug, Default, Copy, Clone)]
 pub struct Duration {
+    /// Whether the duration goes back in time, `-P1D`
+    pub negative: bool,
     /// Number of calendar years
     pub years: u32,
     /// Number of months
@@ -215,29 +764,43 @@ pub struct Duration {
     pub minutes: u32,
     /// Number of seconds
     pub seconds: u32,
-    /// Number of milliseconds
-    pub milliseconds: Option<f32>,
+    /// Fraction of the last second, `.5` in `PT1.5S`
+    pub fraction: Option<Fraction>,
 }
 
-impl PartialEq for Duration {
-    fn eq(&self, other: &Self) -> bool {
-        self.years == other.years
-            && self.months == other.months
-            && self.weeks == other.weeks
-            && self.days == other.days
-            && self.hours == other.hours
-            && self.minutes == other.minutes
-            && self.seconds == other.seconds
-            && self.milliseconds.map(|v| v.to_bits()) == other.milliseconds.map(|v| v.to_bits())
+impl Duration {
+    /// Creates a duration of years, months and days, `P1Y2M3D`.
+    pub fn from_ymd(years: u32, months: u32, days: u32) -> Duration {
+        Duration {
+            years,
+            months,
+            days,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a duration of weeks, `P3W`.
+    pub fn from_weeks(weeks: u32) -> Duration {
+        Duration {
+            weeks,
+            ..Default::default()
+        }
     }
-}
 
-impl Eq for Duration {}
+    /// Creates a duration of hours, minutes and seconds, `PT1H2M3S`.
+    pub fn from_hms(hours: u32, minutes: u32, seconds: u32) -> Duration {
+        Duration {
+            hours,
+            minutes,
+            seconds,
+            ..Default::default()
+        }
+    }
 
-impl Duration {
-    /// Whether this duration represents a zero duration.
+    /// Whether this duration represents a zero duration, whatever its sign.
     pub fn is_zero(&self) -> bool {
         let Duration {
+            negative: _,
             years,
             months,
             weeks,
@@ -245,13 +808,13 @@ impl Duration {
             hours,
             minutes,
             seconds,
-            milliseconds,
+            fraction,
         } = self;
 
         [*years, *months, *weeks, *days, *hours, *minutes, *seconds]
             .iter()
             .all(|&x| x == 0)
-            && milliseconds.unwrap_or(0.0) == 0.0
+            && fraction.is_none_or(|f| f.is_zero())
     }
 
     /// Whether this duration has a time component.
@@ -261,141 +824,166 @@ impl Duration {
             hours,
             minutes,
             seconds,
-            milliseconds,
+            fraction,
             ..
         } = self;
 
         [*days, *hours, *minutes, *seconds].iter().all(|&x| x > 0)
-            || milliseconds.unwrap_or(0.0) > 0.0
+            || fraction.is_some_and(|f| !f.is_zero())
+    }
+
+    /// Balances the time units, carrying seconds into minutes and minutes into hours, so that
+    /// `PT90M` becomes `PT1H30M`. Hours aren't carried into days, nor days into weeks or months.
+    ///
+    /// Returns `None` if the hours don't fit a `u32` anymore.
+    ///
+    /// ```
+    /// use winnow_datetime::Duration;
+    ///
+    /// let duration = Duration::from_hms(0, 90, 3661).normalize().unwrap();
+    /// assert_eq!(duration, Duration::from_hms(2, 31, 1));
+    /// ```
+    pub fn normalize(&self) -> Option<Duration> {
+        let minutes = self.minutes as u64 + self.seconds as u64 / 60;
+        let hours = self.hours as u64 + minutes / 60;
+
+        Some(Duration {
+            hours: u32::try_from(hours).ok()?,
+            minutes: (minutes % 60) as u32,
+            seconds: self.seconds % 60,
+            ..*self
+        })
+    }
+
+    /// Balances the time units like [`Duration::normalize`], and carries months into years as
+    /// well, so that `P13M` becomes `P1Y1M`.
+    pub fn normalize_years(&self) -> Option<Duration> {
+        let years = self.years as u64 + self.months as u64 / 12;
+
+        Some(Duration {
+            years: u32::try_from(years).ok()?,
+            months: self.months % 12,
+            ..self.normalize()?
+        })
+    }
+
+    // the length in seconds, counting years as 365 days and months as 30 days, which can't
+    // overflow as every unit fits a `u32`
+    pub(crate) fn nominal_seconds(&self) -> u64 {
+        (self.years as u64 * 365
+            + self.months as u64 * 30
+            + self.weeks as u64 * 7
+            + self.days as u64)
+            * 86_400
+            + self.hours as u64 * 3600
+            + self.minutes as u64 * 60
+            + self.seconds as u64
     }
 }
 
+/// The length of the duration, counting years as 365 days and months as 30 days. A negative
+/// duration gives the same result as a positive one, see [`Duration::total_seconds`] for a signed
+/// and exact length.
 impl From<Duration> for ::core::time::Duration {
     fn from(duration: Duration) -> Self {
-        let Duration {
-            years,
-            months,
-            weeks,
-            days,
-            hours,
-            minutes,
-            seconds,
-            milliseconds,
-        } = duration;
-
-        let secs = years * 365 * 86_400
-            + months * 30 * 86_400
-            + weeks * 7 * 86_400
-            + days * 86_400
-            + hours * 3600
-            + minutes * 60
-            + seconds;
-        let nanos = (milliseconds.unwrap_or(0.0) * 1_000_000_000.0).floor();
-        Self::new(secs as u64, nanos as u32)
+        Self::new(
+            duration.nominal_seconds(),
+            duration.fraction.map_or(0, |f| f.nanos),
+        )
     }
 }
 
+/// The decimal fraction of a duration component, `.25` in `PT1.25S`.
+///
+/// It is kept exactly, in billionths of the unit, along with the number of digits it was written
+/// with so that it prints back the same way. Digits past the ninth are truncated.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[derive(Debug, Default, Copy, Clone)]
-pub struct DurationPart {
-    pub whole: u32,
-    pub frac: Option<f32>,
+#[derive(Eq, PartialEq, Hash, Debug, Default, Copy, Clone)]
+pub struct Fraction {
+    /// billionths of the unit, `250_000_000` for `.25`
+    pub nanos: u32,
+    /// number of digits the fraction was written with, `0` to use as few as possible
+    pub digits: u8,
 }
 
-impl PartialEq for DurationPart {
-    fn eq(&self, other: &Self) -> bool {
-        self.whole == other.whole
-            && self.frac.map(|f| f.to_bits()) == other.frac.map(|f| f.to_bits())
+impl Fraction {
+    /// Creates a fraction of `nanos` billionths written with `digits` digits.
+    pub const fn new(nanos: u32, digits: u8) -> Fraction {
+        Fraction { nanos, digits }
+    }
+
+    /// Whether the fraction is zero, such as `.0` or `.000`.
+    pub fn is_zero(&self) -> bool {
+        self.nanos == 0
     }
 }
 
-impl Eq for DurationPart {}
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Eq, PartialEq, Hash, Debug, Default, Copy, Clone)]
+pub struct DurationPart {
+    pub whole: u32,
+    pub frac: Option<Fraction>,
+}
 
 /// A time duration with fractional precision.
 ///
 /// ## FractionalDuration Grammar
 ///
-/// | FractionalDuration     | ABNF Description                                     |
-/// | ------------ | ---------------------------------------------------- |
-/// | `dur-second` | 1*DIGIT "S"                                          |
-/// | `dur-minute` | 1*DIGIT "M" [`dur-second`]                           |
-/// | `dur-hour`   | 1*DIGIT "H" [`dur-minute`]                           |
-/// | `dur-time`   | "T" (`dur-hour` / `dur-minute` / `dur-second`)       |
-/// | `dur-day`    | 1*DIGIT "D"                                          |
-/// | `dur-week`   | 1*DIGIT "W"                                          |
-/// | `dur-month`  | 1*DIGIT "M" [`dur-day`]                              |
-/// | `dur-year`   | 1*DIGIT "Y" [`dur-month`]                            |
-/// | `dur-date`   | (`dur-day` / `dur-month` / `dur-year`) [`dur-time`]  |
-/// | `duration`   | "P" (`dur-date` / `dur-time` / `dur-week`)           |
+/// | FractionalDuration     | ABNF Description                                       |
+/// | ------------ | ------------------------------------------------------ |
+/// | `dur-second` | 1*DIGIT "S"                                            |
+/// | `dur-minute` | 1*DIGIT "M" [`dur-second`]                             |
+/// | `dur-hour`   | 1*DIGIT "H" [`dur-minute`]                             |
+/// | `dur-time`   | "T" (`dur-hour` / `dur-minute` / `dur-second`)         |
+/// | `dur-day`    | 1*DIGIT "D"                                            |
+/// | `dur-week`   | 1*DIGIT "W"                                            |
+/// | `dur-month`  | 1*DIGIT "M" [`dur-day`]                                |
+/// | `dur-year`   | 1*DIGIT "Y" [`dur-month`]                              |
+/// | `dur-date`   | (`dur-day` / `dur-month` / `dur-year`) [`dur-time`]    |
+/// | `duration`   | ["+" / "-"] "P" (`dur-date` / `dur-time` / `dur-week`) |
 ///
 /// ## Examples
 /// ```
 /// // 1 year, 11 months, 16 days, 23 hours, 26 minutes, 59 seconds, 123 milliseconds
-/// use winnow_datetime::types::FractionalDuration;
+/// use winnow_datetime::types::{Fraction, FractionalDuration};
 ///
 /// let d = FractionalDuration {
+///      negative: false,
 ///      years: (1, None),
 ///      months: (11, None),
 ///      weeks: (0, None),
 ///      days: (16, None),
 ///      hours: (23, None),
 ///      minutes: (26, None),
-///      seconds: (59, Some(0.123)),
+///      seconds: (59, Some(Fraction::new(123_000_000, 3))),
 /// };
 /// ```
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(default))]
-#[derive(Debug, Default, Copy, Clone)]
+#[derive(Eq, PartialEq, Hash, Debug, Default, Copy, Clone)]
 pub struct FractionalDuration {
+    /// Whether the duration goes back in time, `-P1.5D`
+    pub negative: bool,
     /// Number of calendar years
-    pub years: (u32, Option<f32>),
+    pub years: (u32, Option<Fraction>),
     /// Number of months
-    pub months: (u32, Option<f32>),
+    pub months: (u32, Option<Fraction>),
     /// Number of weeks
-    pub weeks: (u32, Option<f32>),
+    pub weeks: (u32, Option<Fraction>),
     /// Number of days
-    pub days: (u32, Option<f32>),
+    pub days: (u32, Option<Fraction>),
     /// Number of hours
-    pub hours: (u32, Option<f32>),
+    pub hours: (u32, Option<Fraction>),
     /// Number of minutes
-    pub minutes: (u32, Option<f32>),
+    pub minutes: (u32, Option<Fraction>),
     /// Number of seconds
-    pub seconds: (u32, Option<f32>),
-}
-
-impl PartialEq for FractionalDuration {
-    fn eq(&self, other: &Self) -> bool {
-        [
-            self.years,
-            self.months,
-            self.weeks,
-            self.days,
-            self.hours,
-            self.minutes,
-            self.seconds,
-        ]
-        .iter()
-        .zip([
-            other.years,
-            other.months,
-            other.weeks,
-            other.days,
-            other.hours,
-            other.minutes,
-            other.seconds,
-        ])
-        .all(|(part, other_part)| {
-            part.0 == other_part.0
-                && part.1.map(|v| v.to_bits()) == other_part.1.map(|v| v.to_bits())
-        })
-    }
+    pub seconds: (u32, Option<Fraction>),
 }
 
-impl Eq for FractionalDuration {}
-
 impl FractionalDuration {
-    /// Whether this duration represents a zero duration.
+    /// Whether this duration represents a zero duration, whatever its sign.
     pub fn is_zero(&self) -> bool {
         let FractionalDuration {
+            negative: _,
             years,
             months,
             weeks,
@@ -406,7 +994,7 @@ impl FractionalDuration {
         } = self;
         [*years, *months, *weeks, *days, *hours, *minutes, *seconds]
             .iter()
-            .all(|&x| x.0 == 0 && x.1.unwrap_or(0.0) == 0.0)
+            .all(|&(whole, frac)| whole == 0 && frac.is_none_or(|f| f.is_zero()))
     }
 
     /// Whether this duration has a time component.
@@ -420,7 +1008,7 @@ impl FractionalDuration {
         } = self;
         [*days, *hours, *minutes, *seconds]
             .iter()
-            .all(|&x| x.0 > 0 || x.1.unwrap_or(0.0) > 0.0)
+            .all(|&(whole, frac)| whole > 0 || frac.is_some_and(|f| !f.is_zero()))
     }
 }
 
@@ -445,3 +1033,266 @@ pub struct Calendar {
     pub identifier: String,
     pub critical: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn date_accessors_agree_across_variants() {
+        for year in [-1, 0, 2015, 2016, 2020, 2021] {
+            for ordinal in 1..=gregorian::days_in_year(year) {
+                let date = Date::from_ordinal(year, ordinal).unwrap();
+                let ymd = Date::from_ymd(year, date.month(), date.day()).unwrap();
+                let (week_year, week) = date.iso_week();
+                let week = Date::from_iso_week(week_year, week, date.weekday()).unwrap();
+
+                for other in [ymd, week] {
+                    assert_eq!(other.year(), year);
+                    assert_eq!(other.ordinal(), ordinal);
+                    assert_eq!(other.month(), date.month());
+                    assert_eq!(other.day(), date.day());
+                    assert_eq!(other.weekday(), date.weekday());
+                    assert_eq!(other.iso_week(), date.iso_week());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn date_conversions() {
+        let ymd = Date::from_ymd(2023, 2, 8).unwrap();
+        let week = Date::from_iso_week(2023, 6, 3).unwrap();
+        let ordinal = Date::from_ordinal(2023, 39).unwrap();
+
+        for date in [ymd, week, ordinal] {
+            assert!(matches!(
+                date.to_ymd(),
+                Date::YMD {
+                    year: 2023,
+                    month: 2,
+                    day: 8
+                }
+            ));
+            assert!(matches!(
+                date.to_iso_week(),
+                Date::Week {
+                    year: 2023,
+                    week: 6,
+                    day: 3
+                }
+            ));
+            assert!(matches!(
+                date.to_ordinal(),
+                Date::Ordinal {
+                    year: 2023,
+                    day: 39
+                }
+            ));
+        }
+
+        assert_eq!(ymd, week);
+        assert_eq!(week, ordinal);
+        assert_ne!(ymd, Date::from_ymd(2023, 2, 9).unwrap());
+        assert_ne!(week, Date::from_ordinal(2023, 40).unwrap());
+    }
+
+    #[test]
+    fn invalid_date_equality() {
+        let invalid = Date::YMD {
+            year: 2023,
+            month: 2,
+            day: 30,
+        };
+        let ordinal = Date::Ordinal {
+            year: 2023,
+            day: 61,
+        };
+
+        assert_eq!(invalid, invalid);
+        assert_ne!(invalid, ordinal);
+        assert_eq!(ordinal, Date::from_ymd(2023, 3, 2).unwrap());
+        assert_ne!(
+            invalid,
+            Date::YMD {
+                year: 2023,
+                month: 1,
+                day: 61
+            }
+        );
+    }
+
+    #[test]
+    fn week_dates_at_the_limits_of_the_year() {
+        let first = Date::Week {
+            year: i32::MIN,
+            week: 0,
+            day: 1,
+        };
+        let last = Date::Week {
+            year: i32::MAX,
+            week: 54,
+            day: 7,
+        };
+        let past_the_month = Date::YMD {
+            year: i32::MAX,
+            month: 13,
+            day: u32::MAX,
+        };
+
+        for date in [first, last, past_the_month] {
+            assert_eq!(date, date);
+            assert_eq!(date.cmp(&date), core::cmp::Ordering::Equal);
+        }
+        assert!(first < Date::from_ymd(i32::MIN, 1, 1).unwrap());
+        assert!(last > Date::from_ymd(i32::MAX, 12, 31).unwrap());
+        assert_eq!(first.year(), i32::MIN);
+    }
+
+    #[test]
+    fn invalid_date_ordering() {
+        let ymd = |year, month, day| Date::YMD { year, month, day };
+        let mut dates = [
+            ymd(2023, 13, 1),
+            ymd(2023, 3, 2),
+            ymd(2023, 2, 30),
+            Date::Ordinal {
+                year: 2023,
+                day: 61,
+            },
+            ymd(2023, 1, 61),
+            ymd(2023, 12, 31),
+        ];
+
+        for a in &dates {
+            for b in &dates {
+                assert_eq!(a.cmp(b).is_eq(), a == b, "{:?} and {:?}", a, b);
+            }
+        }
+
+        dates.sort();
+        assert_eq!(
+            dates,
+            [
+                ymd(2023, 3, 2),
+                Date::Ordinal {
+                    year: 2023,
+                    day: 61
+                },
+                ymd(2023, 1, 61),
+                ymd(2023, 2, 30),
+                ymd(2023, 12, 31),
+                ymd(2023, 13, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn date_accessors() {
+        let date = Date::from_iso_week(2020, 53, 5).unwrap();
+
+        assert_eq!((date.year(), date.month(), date.day()), (2021, 1, 1));
+        assert_eq!(date.ordinal(), 1);
+        assert_eq!(Date::from_ymd(2024, 12, 31).unwrap().ordinal(), 366);
+        assert_eq!(Date::from_ymd(2024, 12, 30).unwrap().iso_week(), (2025, 1));
+        assert_eq!(Date::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn time_constructors() {
+        let time = Time::from_hms_milli(16, 43, 23, 120).unwrap();
+
+        assert_eq!(time.nanosecond, 120_000_000);
+        assert_eq!(time.to_string(), "16:43:23.120");
+        assert_eq!(Time::from_hms(24, 0, 0), Err(InvalidTime::Hour(24)));
+        assert_eq!(
+            Time::from_hms_milli(0, 0, 0, 1_000),
+            Err(InvalidTime::Millisecond(1_000))
+        );
+        assert!(Time::from_hms(23, 59, 60).is_ok());
+    }
+
+    #[test]
+    fn leap_second_policies() {
+        let time = Time::from_hms(15, 59, 60).unwrap();
+
+        assert_eq!(
+            time.clone().with_leap_second(LeapSecond::RollOver),
+            Time::from_hms(16, 0, 0)
+        );
+        assert_eq!(
+            time.clone()
+                .with_leap_second(LeapSecond::Clamp)
+                .unwrap()
+                .to_string(),
+            "15:59:59.999999999"
+        );
+        assert_eq!(
+            Time::from_hms(15, 59, 59)
+                .unwrap()
+                .with_leap_second(LeapSecond::Reject),
+            Time::from_hms(15, 59, 59)
+        );
+
+        let datetime = DateTime {
+            date: Date::from_iso_week(2020, 53, 7).unwrap(),
+            time: Time::from_hms(23, 59, 60).unwrap(),
+        };
+        let rolled = datetime.with_leap_second(LeapSecond::RollOver).unwrap();
+
+        assert!(matches!(
+            rolled.date,
+            Date::Week {
+                year: 2021,
+                week: 1,
+                day: 1
+            }
+        ));
+        assert_eq!(rolled.time, Time::from_hms(0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn offset_seconds() {
+        assert_eq!(Offset::from_seconds(0), Ok(Offset::UTC));
+        assert_eq!(
+            Offset::from_seconds(19_800).unwrap().seconds(),
+            Some(19_800)
+        );
+        assert_eq!(
+            Offset::from_seconds(86_400),
+            Err(InvalidTime::Offset(86_400))
+        );
+        assert_eq!(Offset::LocalUnknown { critical: false }.seconds(), None);
+    }
+
+    #[test]
+    fn duration_normalize() {
+        let duration = Duration {
+            fraction: Some(Fraction::new(500_000_000, 1)),
+            ..Duration::from_hms(0, 90, 0)
+        };
+        assert_eq!(
+            duration.normalize(),
+            Some(Duration {
+                fraction: Some(Fraction::new(500_000_000, 1)),
+                ..Duration::from_hms(1, 30, 0)
+            })
+        );
+
+        let months = Duration::from_ymd(0, 13, 40);
+        assert_eq!(months.normalize(), Some(months));
+        assert_eq!(months.normalize_years(), Some(Duration::from_ymd(1, 1, 40)));
+
+        assert_eq!(Duration::from_hms(u32::MAX, 60, 0).normalize(), None);
+        assert_eq!(Duration::from_ymd(u32::MAX, 12, 0).normalize_years(), None);
+    }
+
+    #[test]
+    fn duration_std_doesnt_overflow() {
+        let centuries = Duration::from_ymd(500, 0, 0);
+        assert_eq!(
+            ::core::time::Duration::from(centuries).as_secs(),
+            500 * 365 * 86_400
+        );
+    }
+}
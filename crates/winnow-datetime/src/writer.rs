@@ -0,0 +1,339 @@
+//! Building blocks shared by the format specific writers.
+//!
+//! Each format crate exposes a `format` module which decides what its standard allows, these
+//! helpers only know how to put the individual pieces on the wire.
+
+use crate::types::{Fraction, PartialDate};
+use crate::{Calendar, Offset, TimeZone};
+use core::fmt::{self, Write};
+
+/// Whether the components of a value are written with (`2015-06-26`, `16:43:23`) or without
+/// (`20150626`, `164323`) separators.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Notation {
+    /// no separators between the components
+    Basic,
+    /// `-` between date components and `:` between time components
+    #[default]
+    Extended,
+}
+
+impl Notation {
+    /// Separator between the components of a date
+    pub fn date_separator(&self) -> &'static str {
+        match self {
+            Notation::Basic => "",
+            Notation::Extended => "-",
+        }
+    }
+
+    /// Separator between the components of a time or offset
+    pub fn time_separator(&self) -> &'static str {
+        match self {
+            Notation::Basic => "",
+            Notation::Extended => ":",
+        }
+    }
+}
+
+/// Reasons a value can't be written in a given format.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum FormatError {
+    /// the format requires an offset but the time has none
+    MissingOffset,
+    /// the format only supports calendar (year, month, day) dates
+    UnsupportedDate,
+    /// the format can't represent the year
+    YearOutOfRange(i32),
+    /// the underlying writer returned an error
+    Fmt,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::MissingOffset => f.write_str("an offset is required by this format"),
+            FormatError::UnsupportedDate => {
+                f.write_str("only year-month-day dates are supported by this format")
+            }
+            FormatError::YearOutOfRange(year) => {
+                write!(f, "year {} can't be represented by this format", year)
+            }
+            FormatError::Fmt => f.write_str("an error occurred when writing the value"),
+        }
+    }
+}
+
+impl core::error::Error for FormatError {}
+
+impl From<fmt::Error> for FormatError {
+    fn from(_: fmt::Error) -> Self {
+        FormatError::Fmt
+    }
+}
+
+/// Writes a year with at least 4 digits, signed when outside of `0000-9999`.
+pub fn write_year<W: Write>(w: &mut W, year: i32) -> fmt::Result {
+    match year {
+        0..=9999 => write!(w, "{:04}", year),
+        _ if year < 0 => write!(w, "-{:04}", year.unsigned_abs()),
+        _ => write!(w, "+{:04}", year),
+    }
+}
+
+/// Writes a year as exactly 4 digits, failing for years outside of `0000-9999`.
+pub fn write_four_digit_year<W: Write>(w: &mut W, year: i32) -> Result<(), FormatError> {
+    if !(0..=9999).contains(&year) {
+        return Err(FormatError::YearOutOfRange(year));
+    }
+
+    write!(w, "{:04}", year)?;
+
+    Ok(())
+}
+
+/// Writes an expanded year, always signed and with exactly `4 + extra_digits` digits, such as
+/// `+002015` or `-000001`. Fails if the year needs more digits.
+pub fn write_expanded_year<W: Write>(
+    w: &mut W,
+    year: i32,
+    extra_digits: u8,
+) -> Result<(), FormatError> {
+    let digits = 4 + extra_digits as usize;
+
+    if year.unsigned_abs().checked_ilog10().unwrap_or(0) as usize >= digits {
+        return Err(FormatError::YearOutOfRange(year));
+    }
+
+    let sign = if year < 0 { '-' } else { '+' };
+    write!(
+        w,
+        "{}{:0digits$}",
+        sign,
+        year.unsigned_abs(),
+        digits = digits
+    )?;
+
+    Ok(())
+}
+
+/// Writes the fraction of a second with the given number of digits, truncating or padding the
+/// nanoseconds with zeros as needed.
+///
+/// When `digits` is `0` the fraction is only written if it isn't zero, using as few digits as
+/// possible.
+pub fn write_nanosecond<W: Write>(w: &mut W, nanosecond: u32, digits: u8) -> fmt::Result {
+    let digits = match (digits, nanosecond) {
+        (0, 0) => return Ok(()),
+        (0, _) => {
+            let mut digits = 9;
+            let mut n = nanosecond;
+
+            while n.is_multiple_of(10) {
+                n /= 10;
+                digits -= 1;
+            }
+
+            digits
+        }
+        (digits, _) => digits as usize,
+    };
+
+    if digits <= 9 {
+        let value = nanosecond / 10_u32.pow(9 - digits as u32);
+
+        write!(w, ".{:0digits$}", value, digits = digits)
+    } else {
+        write!(w, ".{:09}{:0<pad$}", nanosecond, "", pad = digits - 9)
+    }
+}
+
+/// Writes the decimal point and digits of a duration fraction, if there is one.
+///
+/// A fraction of zero is still written, as `.0` at least, so that it survives being parsed again.
+pub fn write_fraction<W: Write>(w: &mut W, frac: Option<Fraction>) -> fmt::Result {
+    match frac {
+        Some(Fraction {
+            nanos: 0,
+            digits: 0,
+        }) => w.write_str(".0"),
+        Some(frac) => write_nanosecond(w, frac.nanos, frac.digits),
+        None => Ok(()),
+    }
+}
+
+/// Writes an offset as `(+|-)HH:MM` (or `(+|-)HHMM` in basic notation), without using `Z` for
+/// UTC. An unknown local offset is written as `-00:00`.
+pub fn write_numeric_offset<W: Write>(
+    w: &mut W,
+    offset: &Offset,
+    notation: Notation,
+) -> fmt::Result {
+    let separator = notation.time_separator();
+
+    match offset {
+        Offset::LocalUnknown { .. } => write!(w, "-00{}00", separator),
+        Offset::Fixed { hours, minutes, .. } => {
+            let sign = if *hours < 0 || *minutes < 0 { '-' } else { '+' };
+
+            write!(
+                w,
+                "{}{:02}{}{:02}",
+                sign,
+                hours.unsigned_abs(),
+                separator,
+                minutes.unsigned_abs()
+            )
+        }
+    }
+}
+
+/// Writes the components a partial date has, such as the `06-30` ending `2015-06-25/06-30` in
+/// extended notation.
+pub fn write_partial_date<W: Write>(
+    w: &mut W,
+    date: &PartialDate,
+    notation: Notation,
+) -> fmt::Result {
+    let separator = notation.date_separator();
+
+    let (year, first, second) = match *date {
+        PartialDate::Year { year } => (year, None, None),
+        PartialDate::YMD { year, month, day } => {
+            (year, month.map(|m| (m, "", 2)), day.map(|d| (d, "", 2)))
+        }
+        PartialDate::YWD { year, week, day } => {
+            (year, week.map(|w| (w, "W", 2)), day.map(|d| (d, "", 1)))
+        }
+        PartialDate::YDDD { year, day } => (year, day.map(|d| (d, "", 3)), None),
+    };
+
+    let mut needs_separator = false;
+
+    if let Some(year) = year {
+        write_year(w, year)?;
+        needs_separator = true;
+    }
+
+    for (value, prefix, width) in [first, second].into_iter().flatten() {
+        if needs_separator {
+            w.write_str(separator)?;
+        }
+
+        write!(w, "{}{:0width$}", prefix, value, width = width)?;
+        needs_separator = true;
+    }
+
+    Ok(())
+}
+
+/// Writes an offset, using `Z` for UTC.
+pub fn write_offset<W: Write>(w: &mut W, offset: &Offset, notation: Notation) -> fmt::Result {
+    match offset {
+        Offset::Fixed {
+            hours: 0,
+            minutes: 0,
+            ..
+        } => w.write_str("Z"),
+        _ => write_numeric_offset(w, offset, notation),
+    }
+}
+
+fn write_suffix_start<W: Write>(w: &mut W, critical: bool) -> fmt::Result {
+    if critical {
+        w.write_str("[!")
+    } else {
+        w.write_str("[")
+    }
+}
+
+/// Writes a time zone suffix such as `[Europe/Paris]` or `[!+01:00]`.
+pub fn write_time_zone_suffix<W: Write>(w: &mut W, time_zone: &TimeZone) -> fmt::Result {
+    match time_zone {
+        TimeZone::Named { zone } => {
+            write_suffix_start(w, zone.critical)?;
+            w.write_str(&zone.identifier)?;
+        }
+        TimeZone::Fixed { offset } => {
+            let critical = match offset {
+                Offset::Fixed { critical, .. } | Offset::LocalUnknown { critical } => *critical,
+            };
+
+            write_suffix_start(w, critical)?;
+            write_numeric_offset(w, offset, Notation::Extended)?;
+        }
+    }
+
+    w.write_str("]")
+}
+
+/// Writes a calendar suffix such as `[u-ca=gregory]`.
+pub fn write_calendar_suffix<W: Write>(w: &mut W, calendar: &Calendar) -> fmt::Result {
+    write_suffix_start(w, calendar.critical)?;
+    write!(w, "u-ca={}]", calendar.identifier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn years() {
+        let mut s = String::new();
+        write_year(&mut s, -1).unwrap();
+        assert_eq!(s, "-0001");
+
+        assert_eq!(
+            write_four_digit_year(&mut String::new(), 10000),
+            Err(FormatError::YearOutOfRange(10000))
+        );
+
+        let mut s = String::new();
+        write_expanded_year(&mut s, 2015, 2).unwrap();
+        write_expanded_year(&mut s, -1, 2).unwrap();
+        write_expanded_year(&mut s, 12345, 1).unwrap();
+        assert_eq!(s, "+002015-000001+12345");
+
+        assert_eq!(
+            write_expanded_year(&mut String::new(), 123456, 1),
+            Err(FormatError::YearOutOfRange(123456))
+        );
+    }
+
+    #[test]
+    fn nanoseconds() {
+        for (nanosecond, digits, expected) in [
+            (0, 0, ""),
+            (0, 2, ".00"),
+            (120_000_000, 0, ".12"),
+            (123_456_789, 3, ".123"),
+            (123_000_000, 11, ".12300000000"),
+        ] {
+            let mut s = String::new();
+            write_nanosecond(&mut s, nanosecond, digits).unwrap();
+            assert_eq!(s, expected);
+        }
+    }
+
+    #[test]
+    fn offsets() {
+        let offset = Offset::Fixed {
+            hours: -5,
+            minutes: -30,
+            critical: false,
+        };
+
+        let mut s = String::new();
+        write_offset(&mut s, &offset, Notation::Basic).unwrap();
+        assert_eq!(s, "-0530");
+
+        let mut s = String::new();
+        write_offset(
+            &mut s,
+            &Offset::LocalUnknown { critical: false },
+            Notation::Extended,
+        )
+        .unwrap();
+        assert_eq!(s, "-00:00");
+    }
+}
@@ -1,16 +1,16 @@
 use winnow::combinator::separated_pair;
-use winnow::combinator::terminated;
-use winnow::combinator::{alt, eof, opt, preceded, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{opt, preceded, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
+use winnow_datetime::date_ymd_seq;
+use winnow_datetime::gregorian::weeks_in_year;
 use winnow_datetime::parser::date_day;
 use winnow_datetime::parser::digit_1;
 use winnow_datetime::parser::{date_month, digit_4};
-use winnow_datetime::parser::{sign, take_digits_in_range};
+use winnow_datetime::parser::{existing_date, expanded_year, furthest, sign, take_digits_in_range};
 use winnow_datetime::Date;
-use winnow_datetime::{date_ymd_seq, date_ywd_seq};
 
 /// Parses a date string.
 ///
@@ -19,8 +19,63 @@ use winnow_datetime::{date_ymd_seq, date_ywd_seq};
 /// ```rust
 /// let date = winnow_iso8601::parse_date("2015-11-02").unwrap();
 /// ```
-pub fn parse_date(mut i: &str) -> Result<Date, InputError<&str>> {
-    terminated(date, eof).parse_next(&mut i)
+pub fn parse_date(i: &str) -> Result<Date, winnow_datetime::Error> {
+    date::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date from bytes like [`parse_date`], without checking that they are UTF-8 first.
+pub fn parse_date_bytes(i: &[u8]) -> Result<Date, winnow_datetime::Error> {
+    date::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date string, rejecting dates that don't exist in the calendar such as `2015-02-29`.
+///
+/// ## Example
+///
+/// ```rust
+/// assert!(winnow_iso8601::parse_date_strict("2016-02-29").is_ok());
+/// assert!(winnow_iso8601::parse_date_strict("2015-02-29").is_err());
+/// ```
+pub fn parse_date_strict(i: &str) -> Result<Date, winnow_datetime::Error> {
+    date_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date from bytes like [`parse_date_strict`], without checking that they are UTF-8 first.
+pub fn parse_date_strict_bytes(i: &[u8]) -> Result<Date, winnow_datetime::Error> {
+    date_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date string with an expanded year of `4 + extra_digits` digits, which must be signed.
+///
+/// ## Example
+///
+/// ```rust
+/// let date = winnow_iso8601::parse_date_expanded("+012015-06-26", 2).unwrap();
+/// assert_eq!(date, winnow_datetime::Date::YMD { year: 12015, month: 6, day: 26 });
+/// ```
+pub fn parse_date_expanded(i: &str, extra_digits: u8) -> Result<Date, winnow_datetime::Error> {
+    (|input: &mut &str| date_expanded::<_, ContextError>(input, extra_digits))
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date from bytes like [`parse_date_expanded`], without checking that they are UTF-8
+/// first.
+pub fn parse_date_expanded_bytes(
+    i: &[u8],
+    extra_digits: u8,
+) -> Result<Date, winnow_datetime::Error> {
+    (|input: &mut &[u8]| date_expanded::<_, ContextError>(input, extra_digits))
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a date
@@ -37,11 +92,53 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date", move |input: &mut Input| {
-        alt((date_ywd, date_ymd_numeric, date_yddd, date_ymd)).parse_next(input)
+        furthest((date_ywd, date_ymd_numeric, date_yddd, date_ymd)).parse_next(input)
     })
+    .context(StrContext::Label("date"))
+    .parse_next(input)
+}
+
+/// Parses a date like [`date()`], failing when the date doesn't exist in the calendar.
+///
+/// See [`Date::validate`] for the checks applied.
+pub fn date_strict<'i, Input, Error>(input: &mut Input) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("date_strict", move |input: &mut Input| {
+        existing_date(date).parse_next(input)
+    })
+    .context(StrContext::Label("date"))
+    .parse_next(input)
+}
+
+/// Parses a date like [`date()`], but with an expanded year, a sign followed by exactly
+/// `4 + extra_digits` digits such as `+002015-06-26` or `-000001-W01-1`.
+pub fn date_expanded<'i, Input, Error>(input: &mut Input, extra_digits: u8) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    let year = move |input: &mut Input| expanded_year(input, extra_digits);
+
+    trace("date_expanded", move |input: &mut Input| {
+        furthest((
+            |input: &mut Input| date_ywd_with(input, year),
+            |input: &mut Input| date_ymd_numeric_with(input, year),
+            |input: &mut Input| date_yddd_with(input, year),
+            |input: &mut Input| date_ymd_with(input, year),
+        ))
+        .parse_next(input)
+    })
+    .context(StrContext::Label("date"))
     .parse_next(input)
 }
 
@@ -51,7 +148,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_sep", move |input: &mut Input| {
         literal("-").parse_next(input).map(|_| '-')
@@ -59,35 +156,45 @@ where
     .parse_next(input)
 }
 
-/// Parses 2 digit week of the year within range 01-52
+/// Parses 2 digit week of the year, failing if the year doesn't have that week
 // WW
-fn date_week<'i, Input, Error>(input: &mut Input) -> Result<u32, Error>
+fn date_week<'i, Input, Error>(input: &mut Input, year: i32) -> Result<u32, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_week", move |input: &mut Input| {
         let _ = preceded(opt(literal("-")), literal("W")).parse_next(input)?; // [-]Ww
 
-        week_of_year(input)
+        week_of_year
+            .verify(|week| *week <= weeks_in_year(year))
+            .context(StrContext::Expected(StrContextValue::Description(
+                "a week that the year has",
+            )))
+            .context(StrContext::Label("week"))
+            .parse_next(input)
     })
     .parse_next(input)
 }
 
-/// Parses 2 digit week of the year within range 01-52
+/// Parses 2 digit week of the year within range 01-53
 // WW
 pub(crate) fn week_of_year<'i, Input, Error>(input: &mut Input) -> Result<u32, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("week_of_year", move |input: &mut Input| {
-        take_digits_in_range(input, 2, 1..=52)
+        take_digits_in_range(input, 2, 1..=53)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "two digit week from 01 to 53",
+    )))
+    .context(StrContext::Label("week"))
     .parse_next(input)
 }
 
@@ -101,9 +208,9 @@ where
         Some(day) => verify_fn(day),
     }
 }
-/// Verifies a day_of_week range (1-7)
+/// Verifies a `day_of_week` range (1-7)
 fn verify_day_of_week(day: u32) -> bool {
-    day >= 1 && day <= 7
+    (1..=7).contains(&day)
 }
 
 /// Parses 2 digit week of the year within range 01-7
@@ -112,33 +219,53 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("day_of_week", move |input: &mut Input| {
         take_digits_in_range(input, 1, 1..=7)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "day of the week from 1 to 7",
+    )))
+    .context(StrContext::Label("day of week"))
     .parse_next(input)
 }
 
-/// Parses 2 digit week of the year within range 01-52
 /// Parses a date string as ISO 8601 week date.
+///
+/// Week 53 is only accepted in years that have one.
 // YYYY-"W"WW-D
 pub(crate) fn date_ywd<'i, Input, Error>(input: &mut Input) -> Result<Date, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
-    trace("", move |input: &mut Input| {
-        date_ywd_seq!(Date::Week {
-            year: date_year, // y
-            week: date_week, // w
-            day: opt(preceded(opt(date_sep), digit_1))
-                .verify(|d| verify_opt(verify_day_of_week)(*d))
-                .map(|d| d.unwrap_or(1)), // d
-        })
-        .parse_next(input)
+    date_ywd_with(input, date_year)
+}
+
+fn date_ywd_with<'i, Input, Error, Year>(input: &mut Input, mut year: Year) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+    Year: Parser<Input, i32, Error>,
+{
+    trace("date_ywd", move |input: &mut Input| {
+        let year = year.parse_next(input)?; // y
+        let week = date_week(input, year)?; // w
+        let day = opt(preceded(opt(date_sep), digit_1))
+            .verify(|d| verify_opt(verify_day_of_week)(*d))
+            .map(|d| d.unwrap_or(1))
+            .context(StrContext::Expected(StrContextValue::Description(
+                "day of the week from 1 to 7",
+            )))
+            .context(StrContext::Label("day of week"))
+            .parse_next(input)?; // d
+
+        Ok(Date::Week { year, week, day })
     })
     .parse_next(input)
 }
@@ -151,7 +278,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_year", move |input: &mut Input| {
         // The sign is optional, but defaults to `+`
@@ -159,26 +286,43 @@ where
 
         let year = digit_4(input)?;
 
-        if year >= 100 && year < 10000 {
+        if (100..10000).contains(&year) {
             Ok(sign * year as i32)
         } else {
             Err(ParserError::from_input(input))
         }
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "four digit year, optionally signed",
+    )))
+    .context(StrContext::Label("year"))
     .parse_next(input)
 }
 
+// YYYY-MM-DD
 pub(crate) fn date_ymd<'i, Input, Error>(input: &mut Input) -> Result<Date, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
-    // YYYY-MM-DD
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    date_ymd_with(input, date_year)
+}
+
+fn date_ymd_with<'i, Input, Error, Year>(input: &mut Input, mut year: Year) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+    Year: Parser<Input, i32, Error>,
 {
     trace("date_ymd", move |input: &mut Input| {
+        let mut year = year.by_ref();
+
         date_ymd_seq!(Date::YMD {
-            year: date_year,                                                     // YYYY
+            year: year,                                                          // YYYY
             month: preceded(date_sep, date_month),                               // MM
             day: opt(preceded(opt(date_sep), date_day)).map(|d| d.unwrap_or(1)), //DD
         })
@@ -193,11 +337,15 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_day_of_year", move |input: &mut Input| {
         take_digits_in_range(input, 3, 1..=366)
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "three digit day of the year from 001 to 366",
+    )))
+    .context(StrContext::Label("day of year"))
     .parse_next(input)
 }
 
@@ -207,10 +355,21 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    date_yddd_with(input, date_year)
+}
+
+fn date_yddd_with<'i, Input, Error, Year>(input: &mut Input, mut year: Year) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+    Year: Parser<Input, i32, Error>,
 {
     trace("date_yddd", move |input: &mut Input| {
-        separated_pair(date_year, opt(literal("-")), date_day_of_year)
+        separated_pair(year.by_ref(), opt(literal("-")), date_day_of_year)
             .map(|(year, day)| Date::Ordinal { year, day })
             .parse_next(input)
     })
@@ -223,11 +382,27 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    date_ymd_numeric_with(input, date_year)
+}
+
+fn date_ymd_numeric_with<'i, Input, Error, Year>(
+    input: &mut Input,
+    mut year: Year,
+) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+    Year: Parser<Input, i32, Error>,
 {
     trace("date_ymd_numeric", move |input: &mut Input| {
+        let mut year = year.by_ref();
+
         seq!(Date::YMD {
-            year: date_year,   // YYYY
+            year: year,        // YYYY
             month: date_month, // MM
             day: date_day,     //DD
         })
@@ -238,12 +413,14 @@ where
 
 #[cfg(test)]
 mod parsers {
-    use crate::date::{date, date_yddd, date_year, date_ywd, day_of_week};
+    use crate::date::{
+        date, date_expanded, date_strict, date_yddd, date_year, date_ywd, day_of_week,
+    };
     use winnow::error::InputError;
 
     use winnow::Parser;
     use winnow_datetime::parser::{date_day, date_month};
-    use winnow_datetime::PartialInput;
+    use winnow_datetime::{Date, PartialInput};
 
     #[test]
     fn test_date_year() {
@@ -308,6 +485,53 @@ mod parsers {
         assert!(date_ywd::<_, InputError<_>>
             .parse_next(&mut PartialInput::new(b"2015-W542"))
             .is_err());
+        assert!(date_ywd::<_, InputError<_>>
+            .parse_next(&mut PartialInput::new(b"2016-W53-1"))
+            .is_err());
+    }
+
+    #[test]
+    fn expanded_year() {
+        assert_eq!(
+            date_expanded::<_, InputError<_>>(&mut "+002015-06-26", 2).unwrap(),
+            Date::YMD {
+                year: 2015,
+                month: 6,
+                day: 26
+            }
+        );
+        assert_eq!(
+            date_expanded::<_, InputError<_>>(&mut "-10000-001", 1).unwrap(),
+            Date::Ordinal {
+                year: -10000,
+                day: 1
+            }
+        );
+        // the sign is mandatory and the number of digits is fixed
+        assert!(date_expanded::<_, InputError<_>>(&mut "002015-06-26", 2).is_err());
+        assert!(date_expanded::<_, InputError<_>>(&mut "+2015-06-26", 2).is_err());
+        assert!(date_expanded::<_, InputError<_>>(&mut "+0002015-06-26", 2).is_err());
+    }
+
+    #[test]
+    fn week_53() {
+        assert_eq!(
+            date::<_, InputError<_>>(&mut "2020-W53-5").unwrap(),
+            Date::Week {
+                year: 2020,
+                week: 53,
+                day: 5
+            }
+        );
+        assert_eq!(
+            date::<_, InputError<_>>(&mut "2015W537").unwrap(),
+            Date::Week {
+                year: 2015,
+                week: 53,
+                day: 7
+            }
+        );
+        assert!(date::<_, InputError<_>>(&mut "2021-W53-1").is_err());
     }
 
     #[test]
@@ -327,4 +551,53 @@ mod parsers {
     fn disallows_notallowed() {
         assert!(date::<_, InputError<_>>(&mut PartialInput::new(b"0000-20-40")).is_err());
     }
+
+    #[test]
+    fn strict_rejects_missing_days() {
+        for valid in ["2016-02-29", "2016-366", "2015-W53-7", "20151231"] {
+            assert!(date_strict::<_, InputError<_>>(&mut &valid[..]).is_ok());
+        }
+
+        for invalid in ["2015-02-29", "2015-04-31", "2015-366"] {
+            assert!(date::<_, InputError<_>>(&mut &invalid[..]).is_ok());
+            assert!(date_strict::<_, InputError<_>>(&mut &invalid[..]).is_err());
+        }
+
+        let strict_error = |input| {
+            let e = crate::parse_date_strict(input).unwrap_err();
+            (e.offset(), e.component())
+        };
+
+        assert_eq!(strict_error("2015-02-29"), (8, Some("day")));
+        assert_eq!(strict_error("20150229"), (6, Some("day")));
+        assert_eq!(strict_error("2015-366"), (5, Some("day")));
+        assert_eq!(
+            crate::parse_datetime_strict("2015366T10:00Z")
+                .unwrap_err()
+                .component(),
+            Some("day")
+        );
+    }
+
+    #[test]
+    fn week_date_errors() {
+        use std::string::ToString;
+
+        let error = |input| {
+            let e = crate::parse_date(input).unwrap_err();
+            (e.offset(), e.component())
+        };
+
+        assert_eq!(error("2021-W53-5"), (6, Some("week")));
+        assert_eq!(error("2015-W60-1"), (6, Some("week")));
+        assert_eq!(error("2015-W05-8"), (8, Some("day of week")));
+        assert_eq!(error("2015-13-01"), (5, Some("month")));
+
+        assert_eq!(
+            crate::parse_datetime("2021-W53-5T10:00Z")
+                .unwrap_err()
+                .to_string(),
+            "invalid week at byte 6, expected a week that the year has\n  2021-W53-5T10:00Z\n        ^"
+        );
+    }
 }
@@ -1,11 +1,11 @@
 use core::str;
-use winnow::combinator::{eof, opt, preceded, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{fail, opt, preceded, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::{literal, one_of};
 use winnow::{seq, Parser, Result};
 use winnow_datetime::duration_part_seq;
-use winnow_datetime::parser::take_digits;
+use winnow_datetime::parser::{fraction_nanosecond, sign, take_digits};
 use winnow_datetime::types::{Duration, DurationPart};
 
 /// Parses a duration string.
@@ -16,14 +16,26 @@ use winnow_datetime::types::{Duration, DurationPart};
 /// ```rust
 /// let duration = winnow_iso8601::parse_duration("P1Y2M3DT4H5M6S").unwrap();
 /// let duration = winnow_iso8601::parse_duration("P1W").unwrap();
+/// let duration = winnow_iso8601::parse_duration("-P1DT2H").unwrap();
 /// ```
-pub fn parse_duration(mut i: &str) -> Result<Duration, InputError<&str>> {
-    terminated(duration, eof).parse_next(&mut i)
+pub fn parse_duration(i: &str) -> Result<Duration, winnow_datetime::Error> {
+    duration::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a duration from bytes like [`parse_duration`], without checking that they are UTF-8
+/// first.
+pub fn parse_duration_bytes(i: &[u8]) -> Result<Duration, winnow_datetime::Error> {
+    duration::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a duration string with the format P%dY%dM%dDT%dH%dM%dS
 ///
-/// A duration starts with `P` and can have one of the following formats:
+/// A duration starts with `P`, optionally preceded by a `+` or a `-` sign as in Temporal, and can
+/// have one of the following formats:
 ///
 /// * Fully-specified duration: `P1Y2M3DT4H5M6S`
 /// * Duration in weekly intervals: `P1W`
@@ -49,18 +61,20 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration", move |input: &mut Input| {
         seq!((
+            opt(sign).map(|s| s == Some(-1)),
             _: literal("P"),
             opt(duration_part_year),
             opt(duration_part_month),
             opt(duration_part_week),
             opt(duration_part_day),
             opt(preceded(opt(literal("T")), duration_base_time)),
+            _: designator_left,
         ))
-        .verify(|(y, mo, w, d, time)| {
+        .verify(|(_, y, mo, w, d, time)| {
             let (h, m, s) = time.unwrap_or((None, None, None));
 
             let p = [y, mo, w, d, &h, &m];
@@ -68,9 +82,10 @@ where
             (p.iter().any(|x| x.is_some() || s.is_some()))
                 && p.iter().all(|x| x.is_none() || x.unwrap().frac.is_none())
         })
-        .map(|(y, mo, w, d, time)| {
+        .map(|(negative, y, mo, w, d, time)| {
             let time = time.unwrap_or((None, None, None));
             Duration {
+                negative,
                 years: y.map(|p| p.whole).unwrap_or(0),
                 months: mo.map(|p| p.whole).unwrap_or(0),
                 weeks: w.map(|p| p.whole).unwrap_or(0),
@@ -78,12 +93,43 @@ where
                 hours: time.0.map(|p| p.whole).unwrap_or(0),
                 minutes: time.1.map(|p| p.whole).unwrap_or(0),
                 seconds: time.2.map(|p| p.whole).unwrap_or(0),
-                milliseconds: time.2.map(|p| p.frac).unwrap_or(None),
+                fraction: time.2.and_then(|p| p.frac),
             }
             // at least one element must be present for a valid duration representation
         })
         .parse_next(input)
     })
+    .context(StrContext::Label("duration"))
+    .parse_next(input)
+}
+
+// a number left after the parts of a duration has no designator, or one out of order, so fails at
+// the designator rather than leaving the number to whatever follows the duration
+fn designator_left<'i, Input, Error>(input: &mut Input) -> std::result::Result<(), Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("designator_left", move |input: &mut Input| {
+        let number = opt((
+            opt(literal("T")),
+            take_digits,
+            opt(preceded(one_of(b",."), fraction_nanosecond)),
+        ))
+        .parse_next(input)?;
+
+        match number {
+            None => Ok(()),
+            Some(_) => fail
+                .context(StrContext::Expected(StrContextValue::Description(
+                    "a designator after the number, Y, M, W, D, H, M or S in that order",
+                )))
+                .context(StrContext::Label("designator"))
+                .parse_next(input),
+        }
+    })
     .parse_next(input)
 }
 
@@ -95,13 +141,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_year", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("Y")
         })
         .parse_next(input)
@@ -117,13 +163,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_month", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("M")
         })
         .parse_next(input)
@@ -139,13 +185,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_week", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("W")
         })
         .parse_next(input)
@@ -161,13 +207,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_day", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("D")
         })
         .parse_next(input)
@@ -184,13 +230,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_hour", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("H")
         })
         .parse_next(input)
@@ -206,13 +252,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_minute", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("M")
         })
         .parse_next(input)
@@ -228,13 +274,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_part_second", move |input: &mut Input| {
         duration_part_seq!({
             whole: take_digits,
             sep: one_of(b",."),
-            fraction: take_digits,
+            fraction: fraction_nanosecond,
             end: literal("S")
         })
         .parse_next(input)
@@ -242,22 +288,22 @@ where
     .parse_next(input)
 }
 
+/// The optional hour, minute and second parts of a duration's time portion
+pub type DurationTimeParts = (
+    Option<DurationPart>,
+    Option<DurationPart>,
+    Option<DurationPart>,
+);
+
 /// Parses time portion of a duration
 pub fn duration_time<'i, Input, Error>(
     input: &mut Input,
-) -> std::result::Result<
-    (
-        Option<DurationPart>,
-        Option<DurationPart>,
-        Option<DurationPart>,
-    ),
-    Error,
->
+) -> std::result::Result<DurationTimeParts, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_time", move |input: &mut Input| {
         preceded(opt(literal("T")), duration_base_time).parse_next(input)
@@ -267,19 +313,12 @@ where
 
 pub fn duration_base_time<'i, Input, Error>(
     input: &mut Input,
-) -> std::result::Result<
-    (
-        Option<DurationPart>,
-        Option<DurationPart>,
-        Option<DurationPart>,
-    ),
-    Error,
->
+) -> std::result::Result<DurationTimeParts, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("duration_base_time", move |input: &mut Input| {
         seq!((
@@ -298,7 +337,7 @@ where
 mod parsers {
     use crate::duration::*;
     use winnow::error::InputError;
-    use winnow_datetime::types::DurationPart;
+    use winnow_datetime::types::{DurationPart, Fraction};
     use winnow_datetime::PartialInput;
 
     #[test]
@@ -496,14 +535,14 @@ mod parsers {
             duration_part_second::<_, InputError<_>>(&mut "1,23S").unwrap(),
             DurationPart {
                 whole: 1,
-                frac: Some(0.23)
+                frac: Some(Fraction::new(230_000_000, 2))
             }
         );
         assert_eq!(
             duration_part_second::<_, InputError<_>>(&mut "2.34S").unwrap(),
             DurationPart {
                 whole: 2,
-                frac: Some(0.34)
+                frac: Some(Fraction::new(340_000_000, 2))
             }
         );
         assert!(duration_part_second::<_, InputError<_>>(&mut PartialInput::new(b"abS")).is_err());
@@ -585,7 +624,7 @@ mod parsers {
                 }),
                 Some(DurationPart {
                     whole: 3,
-                    frac: Some(0.4)
+                    frac: Some(Fraction::new(400_000_000, 1))
                 })
             )
         );
@@ -599,7 +638,7 @@ mod parsers {
                 None,
                 Some(DurationPart {
                     whole: 23,
-                    frac: Some(0.4)
+                    frac: Some(Fraction::new(400_000_000, 1))
                 })
             )
         );
@@ -610,7 +649,7 @@ mod parsers {
                 None,
                 Some(DurationPart {
                     whole: 0,
-                    frac: Some(0.123)
+                    frac: Some(Fraction::new(123_000_000, 3))
                 })
             )
         );
@@ -636,6 +675,24 @@ mod parsers {
         // missing P,
     }
 
+    #[test]
+    fn duration_designator_error() {
+        use std::string::ToString;
+
+        let error = |input| {
+            let e = crate::parse_duration(input).unwrap_err();
+            (e.offset(), e.component())
+        };
+
+        assert_eq!(error("P1Q"), (2, Some("designator")));
+        assert_eq!(error("P1DT2.5Q"), (7, Some("designator")));
+        assert_eq!(error("P1D2Y"), (4, Some("designator")));
+        assert_eq!(
+            crate::parse_duration("P1Q").unwrap_err().to_string(),
+            "invalid designator at byte 2, expected a designator after the number, Y, M, W, D, H, M or S in that order\n  P1Q\n    ^"
+        );
+    }
+
     #[test]
     fn test_duration_weeks_error() {
         assert!(duration::<_, InputError<_>>(&mut PartialInput::new(b"")).is_err());
@@ -650,6 +707,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT30S").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -657,12 +715,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 30,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT30.123S").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -670,12 +729,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 30,
-                milliseconds: Some(0.123)
+                fraction: Some(Fraction::new(123_000_000, 3))
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT23H26M59.123S").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -683,7 +743,7 @@ mod parsers {
                 hours: 23,
                 minutes: 26,
                 seconds: 59,
-                milliseconds: Some(0.123)
+                fraction: Some(Fraction::new(123_000_000, 3))
             }
         );
     }
@@ -693,6 +753,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P0W").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -700,12 +761,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT23H26M59S").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -713,12 +775,13 @@ mod parsers {
                 hours: 23,
                 minutes: 26,
                 seconds: 59,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT23H26M").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -726,12 +789,13 @@ mod parsers {
                 hours: 23,
                 minutes: 26,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT23H").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -739,12 +803,13 @@ mod parsers {
                 hours: 23,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16D").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -752,12 +817,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT1S").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -765,12 +831,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 1,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M16DT0.471S").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -778,12 +845,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: Some(0.471)
+                fraction: Some(Fraction::new(471_000_000, 3))
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P2021Y11M").unwrap(),
             Duration {
+                negative: false,
                 years: 2021,
                 months: 11,
                 weeks: 0,
@@ -791,12 +859,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P11M").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 11,
                 weeks: 0,
@@ -804,12 +873,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P16D").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -817,12 +887,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P0D").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -830,7 +901,7 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
     }
@@ -840,6 +911,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT12H").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -847,12 +919,13 @@ mod parsers {
                 hours: 12,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT8760H").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -860,7 +933,7 @@ mod parsers {
                 hours: 365 * 24,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
     }
@@ -870,6 +943,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT15M").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -877,12 +951,13 @@ mod parsers {
                 hours: 0,
                 minutes: 15,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT600M").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -890,7 +965,7 @@ mod parsers {
                 hours: 0,
                 minutes: 600,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
     }
@@ -900,6 +975,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT16S").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -907,12 +983,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 16,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "PT900S").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -920,7 +997,7 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 900,
-                milliseconds: None
+                fraction: None
             }
         );
     }
@@ -930,6 +1007,7 @@ mod parsers {
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P365D").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -937,12 +1015,13 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
         assert_eq!(
             duration::<_, InputError<_>>(&mut "P36500D").unwrap(),
             Duration {
+                negative: false,
                 years: 0,
                 months: 0,
                 weeks: 0,
@@ -950,8 +1029,44 @@ mod parsers {
                 hours: 0,
                 minutes: 0,
                 seconds: 0,
-                milliseconds: None
+                fraction: None
             }
         );
     }
+
+    #[test]
+    fn duration_sign() {
+        assert_eq!(
+            duration::<_, InputError<_>>(&mut "-P1DT2H").unwrap(),
+            Duration {
+                negative: true,
+                days: 1,
+                hours: 2,
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            duration::<_, InputError<_>>(&mut "+PT1S").unwrap(),
+            Duration::from_hms(0, 0, 1)
+        );
+        assert!(duration::<_, InputError<_>>(&mut "--P1D").is_err());
+        assert!(duration::<_, InputError<_>>(&mut "P-1D").is_err());
+    }
+
+    #[test]
+    fn duration_exact_fraction() {
+        for (input, fraction) in [
+            ("PT0.1S", Fraction::new(100_000_000, 1)),
+            ("PT1.05S", Fraction::new(50_000_000, 2)),
+            ("PT1,000000001S", Fraction::new(1, 9)),
+        ] {
+            let parsed = duration::<_, InputError<_>>(&mut &*input).unwrap();
+
+            assert_eq!(parsed.fraction, Some(fraction));
+            assert_eq!(
+                crate::format::format_duration(&parsed),
+                Ok(input.replace(',', "."))
+            );
+        }
+    }
 }
@@ -0,0 +1,547 @@
+//! Writers emitting values in the ISO 8601 profile.
+//!
+//! Every writer takes a [`Notation`], choosing between the basic (`20150626T164323+0200`) and
+//! extended (`2015-06-26T16:43:23+02:00`) forms. Time zone and calendar suffixes are RFC 9557
+//! extensions and are not written.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use winnow_iso8601::format::{format_datetime, Notation};
+//!
+//! let dt = winnow_iso8601::parse_datetime("2015-06-26T16:43:23+02:00").unwrap();
+//!
+//! assert_eq!(format_datetime(&dt, Notation::Basic).unwrap(), "20150626T164323+0200");
+//! ```
+
+use core::fmt::Write;
+use winnow_datetime::types::{IntervalRange, PartialDateTime, PartialTime};
+use winnow_datetime::writer::{
+    write_expanded_year, write_nanosecond, write_numeric_offset, write_partial_date, write_year,
+};
+pub use winnow_datetime::writer::{FormatError, Notation};
+use winnow_datetime::{Date, DateTime, Duration, Interval, Offset, Time};
+
+#[cfg(feature = "std")]
+use std::string::String;
+
+/// Writes a date, `2015-06-26`, `2015-W05-6` or `2015-056` in extended notation.
+pub fn write_date<W: Write>(w: &mut W, date: &Date, notation: Notation) -> Result<(), FormatError> {
+    write_date_with(w, date, &notation.into())
+}
+
+/// Writes a date according to the given [`FormatOptions`], only the notation and the year style
+/// apply.
+pub fn write_date_with<W: Write>(
+    w: &mut W,
+    date: &Date,
+    options: &FormatOptions,
+) -> Result<(), FormatError> {
+    let separator = options.notation.date_separator();
+
+    let year = match date {
+        Date::YMD { year, .. } | Date::Week { year, .. } | Date::Ordinal { year, .. } => *year,
+    };
+
+    match options.year {
+        YearStyle::Standard => write_year(w, year)?,
+        YearStyle::Expanded(extra_digits) => write_expanded_year(w, year, extra_digits)?,
+    }
+
+    match date {
+        Date::YMD { month, day, .. } => {
+            write!(w, "{}{:02}{}{:02}", separator, month, separator, day)?;
+        }
+        Date::Week { week, day, .. } => {
+            write!(w, "{}W{:02}{}{}", separator, week, separator, day)?;
+        }
+        Date::Ordinal { day, .. } => {
+            write!(w, "{}{:03}", separator, day)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// How the year of a date is written.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum YearStyle {
+    /// four digits, only signed and longer when the year is outside of `0000-9999`
+    #[default]
+    Standard,
+    /// always signed with exactly `4 + n` digits, `+002015` with two extra digits, as read by
+    /// [`parse_date_expanded`](crate::parse_date_expanded)
+    Expanded(u8),
+}
+
+/// How the date and the time of a datetime are separated.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum DateTimeSeparator {
+    /// `2015-06-26T16:43:23Z`
+    #[default]
+    T,
+    /// `2015-06-26 16:43:23Z`
+    Space,
+}
+
+/// How many digits of the second's fraction are written.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Precision {
+    /// as many digits as the time was parsed with, see [`Time::fraction_digits`]
+    #[default]
+    Auto,
+    /// exactly this many digits, truncating or padding with zeros, `0` drops the fraction
+    Digits(u8),
+}
+
+/// How an offset is written.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum OffsetStyle {
+    /// `Z` for UTC, otherwise `+02:00` or `+0200` following the notation
+    #[default]
+    Zulu,
+    /// always `(+|-)HH:MM`, including `+00:00` for UTC
+    Colon,
+    /// always `(+|-)HHMM`, including `+0000` for UTC
+    NoColon,
+}
+
+/// Options controlling the output of [`write_datetime_with`], [`write_date_with`] and
+/// [`write_time_with`].
+///
+/// The default matches [`write_datetime`] in extended notation.
+///
+/// ## Example
+///
+/// ```rust
+/// use winnow_iso8601::format::{format_datetime_with, DateTimeSeparator, FormatOptions, Precision};
+///
+/// let dt = winnow_iso8601::parse_datetime("2015-06-26T16:43:23Z").unwrap();
+/// let options = FormatOptions {
+///     separator: DateTimeSeparator::Space,
+///     precision: Precision::Digits(3),
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(format_datetime_with(&dt, &options).unwrap(), "2015-06-26 16:43:23.000Z");
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct FormatOptions {
+    /// basic or extended notation
+    pub notation: Notation,
+    /// separator between the date and the time
+    pub separator: DateTimeSeparator,
+    /// digits of the fractional second
+    pub precision: Precision,
+    /// how the offset is written
+    pub offset: OffsetStyle,
+    /// how the year is written
+    pub year: YearStyle,
+}
+
+impl From<Notation> for FormatOptions {
+    fn from(notation: Notation) -> Self {
+        FormatOptions {
+            notation,
+            ..Default::default()
+        }
+    }
+}
+
+/// Writes an offset, `Z` for UTC. ISO 8601 has no notion of an unknown local offset, so
+/// [`Offset::LocalUnknown`] is written as a local time without any offset.
+pub fn write_offset<W: Write>(
+    w: &mut W,
+    offset: &Offset,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    write_offset_with(w, offset, OffsetStyle::Zulu, notation)
+}
+
+fn write_offset_with<W: Write>(
+    w: &mut W,
+    offset: &Offset,
+    style: OffsetStyle,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    match (offset, style) {
+        (Offset::LocalUnknown { .. }, _) => {}
+        (_, OffsetStyle::Zulu) => winnow_datetime::writer::write_offset(w, offset, notation)?,
+        (_, OffsetStyle::Colon) => write_numeric_offset(w, offset, Notation::Extended)?,
+        (_, OffsetStyle::NoColon) => write_numeric_offset(w, offset, Notation::Basic)?,
+    }
+
+    Ok(())
+}
+
+fn write_fraction<W: Write>(
+    w: &mut W,
+    time: &Time,
+    precision: Precision,
+) -> Result<(), FormatError> {
+    match precision {
+        Precision::Auto => write_nanosecond(w, time.nanosecond, time.fraction_digits)?,
+        Precision::Digits(0) => {}
+        Precision::Digits(digits) => write_nanosecond(w, time.nanosecond, digits)?,
+    }
+
+    Ok(())
+}
+
+/// Writes a time, `16:43:23.123+02:00` in extended notation.
+pub fn write_time<W: Write>(w: &mut W, time: &Time, notation: Notation) -> Result<(), FormatError> {
+    write_time_with(w, time, &notation.into())
+}
+
+/// Writes a time according to the given [`FormatOptions`].
+pub fn write_time_with<W: Write>(
+    w: &mut W,
+    time: &Time,
+    options: &FormatOptions,
+) -> Result<(), FormatError> {
+    let separator = options.notation.time_separator();
+
+    write!(
+        w,
+        "{:02}{}{:02}{}{:02}",
+        time.hour, separator, time.minute, separator, time.second
+    )?;
+    write_fraction(w, time, options.precision)?;
+
+    if let Some(offset) = &time.offset {
+        write_offset_with(w, offset, options.offset, options.notation)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a datetime, `2015-06-26T16:43:23+02:00` in extended notation.
+pub fn write_datetime<W: Write>(
+    w: &mut W,
+    datetime: &DateTime,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    write_datetime_with(w, datetime, &notation.into())
+}
+
+/// Writes a datetime according to the given [`FormatOptions`].
+pub fn write_datetime_with<W: Write>(
+    w: &mut W,
+    datetime: &DateTime,
+    options: &FormatOptions,
+) -> Result<(), FormatError> {
+    write_date_with(w, &datetime.date, options)?;
+
+    match options.separator {
+        DateTimeSeparator::T => w.write_str("T")?,
+        DateTimeSeparator::Space => w.write_str(" ")?,
+    }
+
+    write_time_with(w, &datetime.time, options)
+}
+
+/// Writes a duration such as `P1Y2M3DT4H5M6S`, which is the same in both notations.
+pub fn write_duration<W: Write>(w: &mut W, duration: &Duration) -> Result<(), FormatError> {
+    write!(w, "{}", duration)?;
+
+    Ok(())
+}
+
+fn write_partial_time<W: Write>(
+    w: &mut W,
+    time: &PartialTime,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    let separator = notation.time_separator();
+
+    if let Some(hour) = time.hour {
+        write!(w, "{:02}", hour)?;
+    }
+
+    if let Some(minute) = time.minute {
+        write!(w, "{}{:02}", separator, minute)?;
+    }
+
+    if let Some(second) = time.second {
+        write!(w, "{}{:02}", separator, second)?;
+    }
+
+    if let Some(nanosecond) = time.nanosecond {
+        write_nanosecond(w, nanosecond, time.fraction_digits)?;
+    }
+
+    if let Some(offset) = &time.offset {
+        write_offset(w, offset, notation)?;
+    }
+
+    Ok(())
+}
+
+fn write_partial_datetime<W: Write>(
+    w: &mut W,
+    datetime: &PartialDateTime,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    if let Some(date) = &datetime.date {
+        write_partial_date(w, date, notation)?;
+    }
+
+    if let Some(time) = &datetime.time {
+        w.write_str("T")?;
+        write_partial_time(w, time, notation)?;
+    }
+
+    Ok(())
+}
+
+/// Writes an interval, such as `R5/2015-06-25T12:00/P1M` in extended notation.
+pub fn write_interval<W: Write>(
+    w: &mut W,
+    interval: &Interval,
+    notation: Notation,
+) -> Result<(), FormatError> {
+    match interval.repetitions {
+        Some(Some(n)) => write!(w, "R{}/", n)?,
+        Some(None) => w.write_str("R/")?,
+        None => {}
+    }
+
+    match &interval.range {
+        IntervalRange::Closed { start, end } => {
+            write_partial_datetime(w, start, notation)?;
+            w.write_str("/")?;
+            write_partial_datetime(w, end, notation)
+        }
+        IntervalRange::ClosedStart { start, duration } => {
+            write_partial_datetime(w, start, notation)?;
+            w.write_str("/")?;
+            write_duration(w, duration)
+        }
+        IntervalRange::ClosedEnd { duration, end } => {
+            write_duration(w, duration)?;
+            w.write_str("/")?;
+            write_partial_datetime(w, end, notation)
+        }
+        IntervalRange::Open { duration } => write_duration(w, duration),
+    }
+}
+
+/// Formats a date into a new `String`, see [`write_date`].
+#[cfg(feature = "std")]
+pub fn format_date(date: &Date, notation: Notation) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_date(&mut s, date, notation)?;
+    Ok(s)
+}
+
+/// Formats a date into a new `String`, see [`write_date_with`].
+#[cfg(feature = "std")]
+pub fn format_date_with(date: &Date, options: &FormatOptions) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_date_with(&mut s, date, options)?;
+    Ok(s)
+}
+
+/// Formats an offset into a new `String`, see [`write_offset`].
+#[cfg(feature = "std")]
+pub fn format_offset(offset: &Offset, notation: Notation) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_offset(&mut s, offset, notation)?;
+    Ok(s)
+}
+
+/// Formats a time into a new `String`, see [`write_time`].
+#[cfg(feature = "std")]
+pub fn format_time(time: &Time, notation: Notation) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_time(&mut s, time, notation)?;
+    Ok(s)
+}
+
+/// Formats a datetime into a new `String`, see [`write_datetime`].
+#[cfg(feature = "std")]
+pub fn format_datetime(datetime: &DateTime, notation: Notation) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_datetime(&mut s, datetime, notation)?;
+    Ok(s)
+}
+
+/// Formats a time into a new `String`, see [`write_time_with`].
+#[cfg(feature = "std")]
+pub fn format_time_with(time: &Time, options: &FormatOptions) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_time_with(&mut s, time, options)?;
+    Ok(s)
+}
+
+/// Formats a datetime into a new `String`, see [`write_datetime_with`].
+#[cfg(feature = "std")]
+pub fn format_datetime_with(
+    datetime: &DateTime,
+    options: &FormatOptions,
+) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_datetime_with(&mut s, datetime, options)?;
+    Ok(s)
+}
+
+/// Formats a duration into a new `String`, see [`write_duration`].
+#[cfg(feature = "std")]
+pub fn format_duration(duration: &Duration) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_duration(&mut s, duration)?;
+    Ok(s)
+}
+
+/// Formats an interval into a new `String`, see [`write_interval`].
+#[cfg(feature = "std")]
+pub fn format_interval(interval: &Interval, notation: Notation) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_interval(&mut s, interval, notation)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        interval::parse_interval, parse_date, parse_date_expanded, parse_datetime,
+        parse_datetime_expanded, parse_time,
+    };
+
+    #[test]
+    fn round_trips_in_both_notations() {
+        for (extended, basic) in [
+            ("2015-06-26T16:43:23+02:00", "20150626T164323+0200"),
+            ("2015-W05-6T04:05:06.123Z", "2015W056T040506.123Z"),
+            ("2015-056T16:43:23-05:30", "2015056T164323-0530"),
+        ] {
+            let dt = parse_datetime(extended).unwrap();
+
+            assert_eq!(format_datetime(&dt, Notation::Extended).unwrap(), extended);
+            assert_eq!(format_datetime(&dt, Notation::Basic).unwrap(), basic);
+            assert_eq!(parse_datetime(basic).unwrap(), dt);
+        }
+    }
+
+    #[test]
+    fn date_and_time() {
+        let date = parse_date("-0333-07-11").unwrap();
+        assert_eq!(format_date(&date, Notation::Basic).unwrap(), "-03330711");
+
+        let time = parse_time("16:43").unwrap();
+        assert_eq!(format_time(&time, Notation::Extended).unwrap(), "16:43:00");
+    }
+
+    #[test]
+    fn options() {
+        let dt = parse_datetime("2015-06-26T16:43:23.120Z").unwrap();
+
+        for (options, expected) in [
+            (
+                FormatOptions {
+                    notation: Notation::Basic,
+                    ..Default::default()
+                },
+                "20150626T164323.120Z",
+            ),
+            (
+                FormatOptions {
+                    separator: DateTimeSeparator::Space,
+                    offset: OffsetStyle::Colon,
+                    ..Default::default()
+                },
+                "2015-06-26 16:43:23.120+00:00",
+            ),
+            (
+                FormatOptions {
+                    precision: Precision::Digits(0),
+                    offset: OffsetStyle::NoColon,
+                    ..Default::default()
+                },
+                "2015-06-26T16:43:23+0000",
+            ),
+            (
+                FormatOptions {
+                    precision: Precision::Digits(1),
+                    ..Default::default()
+                },
+                "2015-06-26T16:43:23.1Z",
+            ),
+            (
+                FormatOptions {
+                    notation: Notation::Basic,
+                    precision: Precision::Digits(6),
+                    offset: OffsetStyle::Colon,
+                    ..Default::default()
+                },
+                "20150626T164323.120000+00:00",
+            ),
+        ] {
+            let formatted = format_datetime_with(&dt, &options).unwrap();
+
+            assert_eq!(formatted, expected);
+            assert_eq!(
+                parse_datetime(&formatted).unwrap().date,
+                dt.date,
+                "{}",
+                formatted
+            );
+        }
+    }
+
+    #[test]
+    fn expanded_years() {
+        for (extended, basic) in [
+            ("+012015-06-26", "+0120150626"),
+            ("-000001-W52-7", "-000001W527"),
+            ("+000000-366", "+000000366"),
+        ] {
+            let date = parse_date_expanded(extended, 2).unwrap();
+            let mut options = FormatOptions {
+                year: YearStyle::Expanded(2),
+                ..Default::default()
+            };
+
+            assert_eq!(format_date_with(&date, &options).unwrap(), extended);
+
+            options.notation = Notation::Basic;
+            assert_eq!(format_date_with(&date, &options).unwrap(), basic);
+            assert_eq!(parse_date_expanded(basic, 2).unwrap(), date);
+        }
+
+        let dt = parse_datetime_expanded("+12015-06-26T16:43:23Z", 1).unwrap();
+        let options = FormatOptions {
+            year: YearStyle::Expanded(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            format_datetime_with(&dt, &options).unwrap(),
+            "+12015-06-26T16:43:23Z"
+        );
+        assert_eq!(
+            format_date_with(
+                &dt.date,
+                &FormatOptions {
+                    year: YearStyle::Expanded(0),
+                    ..Default::default()
+                }
+            ),
+            Err(FormatError::YearOutOfRange(12015))
+        );
+    }
+
+    #[test]
+    fn interval() {
+        let interval = parse_interval("R5/2015-06-25T12:00/P1M").unwrap();
+
+        assert_eq!(
+            format_interval(&interval, Notation::Extended).unwrap(),
+            "R5/2015-06-25T12:00/P1M"
+        );
+        assert_eq!(
+            format_interval(&interval, Notation::Basic).unwrap(),
+            "R5/20150625T1200/P1M"
+        );
+    }
+}
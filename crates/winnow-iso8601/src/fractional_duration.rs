@@ -2,18 +2,37 @@ use crate::duration::{
     duration_base_time, duration_part_day, duration_part_month, duration_part_week,
     duration_part_year,
 };
-use winnow::combinator::{eof, opt, preceded, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{opt, preceded, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
+use winnow_datetime::parser::sign;
 use winnow_datetime::FractionalDuration;
 
 /// Parses a duration with the same formating rules but allows for decimal places.
+///
+/// ## Examples
+///
+/// ```rust
 /// let duration = winnow_iso8601::parse_fractional_duration("P1,5Y2M3DT4,5H5M6S").unwrap();
 /// let duration = winnow_iso8601::parse_fractional_duration("P1,5W").unwrap();
-pub fn parse_fractional_duration(mut i: &str) -> Result<FractionalDuration, InputError<&str>> {
-    terminated(fractional_duration, eof).parse_next(&mut i)
+/// let duration = winnow_iso8601::parse_fractional_duration("-PT0,5S").unwrap();
+/// ```
+pub fn parse_fractional_duration(i: &str) -> Result<FractionalDuration, winnow_datetime::Error> {
+    fractional_duration::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a fractional duration from bytes like [`parse_fractional_duration`], without checking
+/// that they are UTF-8 first.
+pub fn parse_fractional_duration_bytes(
+    i: &[u8],
+) -> Result<FractionalDuration, winnow_datetime::Error> {
+    fractional_duration::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a duration string with the format P%dY%dM%dDT%dH%dM%dS
@@ -24,10 +43,11 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("fractional_duration", move |input: &mut Input| {
         seq!((
+            opt(sign).map(|s| s == Some(-1)),
             _: literal("P"),
             opt(duration_part_year),
             opt(duration_part_month),
@@ -35,17 +55,14 @@ where
             opt(duration_part_day),
             opt(preceded(opt(literal("T")), duration_base_time)),
         ))
-        .verify(|(y, mo, w, d, time)| {
-            if y.is_none() && mo.is_none() && w.is_none() && d.is_none() && time.is_none() {
-                false
-            } else {
-                true
-            }
+        .verify(|(_, y, mo, w, d, time)| {
+            !(y.is_none() && mo.is_none() && w.is_none() && d.is_none() && time.is_none())
         })
-        .map(|(y, mo, w, d, time)| {
+        .map(|(negative, y, mo, w, d, time)| {
             let time = time.unwrap_or((None, None, None));
 
             FractionalDuration {
+                negative,
                 years: y.map(|p| (p.whole, p.frac)).unwrap_or((0, None)),
                 months: mo.map(|p| (p.whole, p.frac)).unwrap_or((0, None)),
                 weeks: w.map(|p| (p.whole, p.frac)).unwrap_or((0, None)),
@@ -58,5 +75,6 @@ where
         })
         .parse_next(input)
     })
+    .context(StrContext::Label("duration"))
     .parse_next(input)
 }
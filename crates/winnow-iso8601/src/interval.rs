@@ -2,21 +2,30 @@ use crate::duration::duration;
 use crate::partial_datetime::{partial_datetime, partial_end_datetime};
 use winnow::combinator::opt;
 use winnow::combinator::trace;
-use winnow::combinator::{alt, eof, terminated};
-use winnow::error::{InputError, ParserError};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
-use winnow_datetime::parser::take_digits;
-use winnow_datetime::types::{Interval, IntervalRange};
+use winnow_datetime::parser::{furthest, take_digits};
+use winnow_datetime::types::{Duration, Interval, IntervalRange};
 
 /// Parses an interval
 ///
 /// A string that optionally starts with `R` and contains a combination of partial date-times in the
 /// following permissible formats:
 ///
-pub fn parse_interval(mut i: &str) -> Result<Interval, InputError<&str>> {
-    terminated(interval, eof).parse_next(&mut i)
+pub fn parse_interval(i: &str) -> Result<Interval, winnow_datetime::Error> {
+    interval::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses an interval from bytes like [`parse_interval`], without checking that they are UTF-8
+/// first.
+pub fn parse_interval_bytes(i: &[u8]) -> Result<Interval, winnow_datetime::Error> {
+    interval::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a interval string containing combinations of partial date-times and duration.
@@ -25,12 +34,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval", move |input: &mut Input| {
         seq!(Interval {
             repetitions: opt(interval_repetitions),
-            range: alt((
+            range: furthest((
                 interval_closed,
                 interval_closed_end,
                 interval_closed_start,
@@ -39,6 +48,7 @@ where
         })
         .parse_next(input)
     })
+    .context(StrContext::Label("interval"))
     .parse_next(input)
 }
 
@@ -47,25 +57,52 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval_repetitions", move |input: &mut Input| {
-        seq!((literal("R"), opt(take_digits), literal("/")))
+        seq!((literal("R"), opt(take_digits), interval_sep))
             .map(|(_, r, _)| r)
             .parse_next(input)
     })
     .parse_next(input)
 }
 
+// the `/` between the parts of an interval
+fn interval_sep<'i, Input, Error>(input: &mut Input) -> Result<(), Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("interval_sep", literal("/").void())
+        .context(StrContext::Expected(StrContextValue::CharLiteral('/')))
+        .parse_next(input)
+}
+
+// the duration of an interval, which can't be negative
+fn interval_duration<'i, Input, Error>(input: &mut Input) -> Result<Duration, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("interval_duration", move |input: &mut Input| {
+        duration
+            .verify(|duration: &Duration| !duration.negative)
+            .parse_next(input)
+    })
+    .parse_next(input)
+}
+
 fn interval_open<'i, Input, Error>(input: &mut Input) -> Result<IntervalRange, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval_open", move |input: &mut Input| {
-        duration(input).map(|duration| IntervalRange::Open { duration })
+        interval_duration(input).map(|duration| IntervalRange::Open { duration })
     })
     .parse_next(input)
 }
@@ -75,11 +112,11 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval_closed", move |input: &mut Input| {
         let start = partial_datetime(input)?;
-        let _ = literal("/").parse_next(input)?;
+        interval_sep(input)?;
         let end = partial_end_datetime(input, &start)?;
 
         Ok(IntervalRange::Closed { start, end })
@@ -92,12 +129,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval_closed_end", move |input: &mut Input| {
         seq!(IntervalRange::ClosedEnd {
-            duration: duration,
-            _: literal("/"),
+            duration: interval_duration,
+            _: interval_sep,
             end: partial_datetime,
         })
         .parse_next(input)
@@ -110,13 +147,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("interval_closed_start", move |input: &mut Input| {
         seq!( IntervalRange::ClosedStart {
             start: partial_datetime,
-            _: literal("/"),
-            duration: duration,
+            _: interval_sep,
+            duration: interval_duration,
         })
         .parse_next(input)
     })
@@ -174,7 +211,8 @@ mod parsers {
                             hour: Some(12),
                             minute: Some(0),
                             second: Some(0),
-                            millisecond: None,
+                            nanosecond: None,
+                            fraction_digits: 0,
                             offset: Some(Offset::Fixed {
                                 hours: 0,
                                 minutes: 0,
@@ -192,7 +230,8 @@ mod parsers {
                             hour: Some(12),
                             minute: Some(0),
                             second: Some(0),
-                            millisecond: None,
+                            nanosecond: None,
+                            fraction_digits: 0,
                             offset: Some(Offset::Fixed {
                                 hours: 0,
                                 minutes: 0,
@@ -415,6 +454,7 @@ mod parsers {
                 repetitions: None,
                 range: IntervalRange::Open {
                     duration: Duration {
+                        negative: false,
                         years: 1,
                         months: 2,
                         weeks: 0,
@@ -422,7 +462,7 @@ mod parsers {
                         hours: 0,
                         minutes: 0,
                         seconds: 0,
-                        milliseconds: None,
+                        fraction: None,
                     },
                 },
             }
@@ -445,6 +485,7 @@ mod parsers {
                         time: None,
                     },
                     duration: Duration {
+                        negative: false,
                         years: 0,
                         months: 1,
                         weeks: 0,
@@ -452,7 +493,7 @@ mod parsers {
                         hours: 0,
                         minutes: 0,
                         seconds: 0,
-                        milliseconds: None,
+                        fraction: None,
                     },
                 },
             }
@@ -467,6 +508,7 @@ mod parsers {
                 repetitions: None,
                 range: IntervalRange::ClosedEnd {
                     duration: Duration {
+                        negative: false,
                         years: 0,
                         months: 1,
                         weeks: 0,
@@ -474,7 +516,7 @@ mod parsers {
                         hours: 0,
                         minutes: 0,
                         seconds: 0,
-                        milliseconds: None,
+                        fraction: None,
                     },
                     end: PartialDateTime {
                         date: Some(PartialDate::YMD {
@@ -488,4 +530,112 @@ mod parsers {
             }
         )
     }
+
+    #[test]
+    fn interval_closed_end_time_only() {
+        let time = |hour| PartialTime {
+            hour: Some(hour),
+            minute: Some(0),
+            second: None,
+            nanosecond: None,
+            fraction_digits: 0,
+            offset: None,
+        };
+        let closed = |start_date, end_date| Interval {
+            repetitions: None,
+            range: IntervalRange::Closed {
+                start: PartialDateTime {
+                    date: start_date,
+                    time: Some(time(10)),
+                },
+                end: PartialDateTime {
+                    date: end_date,
+                    time: Some(time(12)),
+                },
+            },
+        };
+        let date = PartialDate::YMD {
+            year: Some(2015),
+            month: Some(6),
+            day: Some(25),
+        };
+
+        assert_eq!(
+            interval::<_, InputError<_>>(&mut "2015-06-25T10:00/12:00").unwrap(),
+            closed(Some(date), None)
+        );
+        assert_eq!(
+            interval::<_, InputError<_>>(&mut "2015-06-25T10:00/T12:00").unwrap(),
+            closed(Some(date), None)
+        );
+        assert_eq!(
+            interval::<_, InputError<_>>(&mut "2015-06-25T10:00/2015-06-25T12:00").unwrap(),
+            closed(Some(date), Some(date))
+        );
+        assert_eq!(
+            interval::<_, InputError<_>>(&mut "T10:00/T12:00").unwrap(),
+            closed(None, None)
+        );
+    }
+
+    #[test]
+    fn interval_sub_millisecond_ends() {
+        use crate::format::{format_interval, Notation};
+        use crate::interval::parse_interval;
+        use std::string::ToString;
+
+        for input in [
+            "2015-06-25T12:00:00.123456Z/PT1S",
+            "2015-06-25T12:00:00.1Z/2015-06-25T12:00:01.123456789Z",
+        ] {
+            let interval = parse_interval(input).unwrap();
+
+            assert_eq!(
+                format_interval(&interval, Notation::Extended).unwrap(),
+                input
+            );
+            assert_eq!(interval.to_string(), input);
+        }
+
+        let (start, _) = parse_interval("2015-06-25T12:00:00.123456Z/PT1S")
+            .unwrap()
+            .resolve()
+            .unwrap();
+        assert_eq!(start.to_string(), "2015-06-25T12:00:00.123456Z");
+    }
+
+    #[test]
+    fn interval_negative_duration() {
+        assert!(interval::<_, InputError<_>>(&mut "-P1M/2015-06-25").is_err());
+        assert!(interval::<_, InputError<_>>(&mut "2015-06-25/-P1M").is_err());
+        assert!(interval::<_, InputError<_>>(&mut "-P1M").is_err());
+    }
+
+    #[test]
+    fn interval_errors() {
+        use std::string::ToString;
+
+        let error = |input| {
+            let e = crate::interval::parse_interval(input).unwrap_err();
+            (e.offset(), e.component())
+        };
+
+        assert_eq!(error("2015-06-25/2015-13-01"), (16, Some("month")));
+        assert_eq!(error("2015-06-25/06-32"), (14, Some("day")));
+        assert_eq!(error("2015-W05-1/W60-1"), (12, Some("week")));
+        assert_eq!(error("2015-06-25T25:00/P1D"), (11, Some("hour")));
+        assert_eq!(error("2015-06-25/P1X"), (13, Some("designator")));
+        assert_eq!(
+            error("2015-06-25T10:00/2015-06-26T25:00"),
+            (28, Some("hour"))
+        );
+        assert_eq!(error("2015-06-25T10:00/12:60"), (20, Some("minute")));
+
+        assert_eq!(
+            crate::interval::parse_interval("R5/2015-06-25")
+                .unwrap_err()
+                .to_string(),
+            "invalid interval at byte 13, expected `/`\n  R5/2015-06-25\n               ^"
+        );
+    }
 }
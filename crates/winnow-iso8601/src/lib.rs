@@ -4,7 +4,8 @@
 //!
 //! Validity of a given date is not guaranteed, this parser will happily parse
 //! `"2015-02-29"` as a valid date,
-//! even though 2015 was no leap year.
+//! even though 2015 was no leap year. Use [`parse_date_strict`] and [`parse_datetime_strict`], or
+//! [`Date::validate`](winnow_datetime::Date::validate), to reject dates that don't exist.
 //!
 //! # Example
 //!
@@ -23,19 +24,28 @@ mod clippy;
 
 /// date mod
 pub mod date;
-pub use date::parse_date;
+pub use date::{
+    parse_date, parse_date_bytes, parse_date_expanded, parse_date_expanded_bytes,
+    parse_date_strict, parse_date_strict_bytes,
+};
 
 /// time mod
 pub mod time;
-pub use time::parse_time;
+pub use time::{parse_time, parse_time_bytes};
 
 /// datetime mod
 pub mod datetime;
-pub use datetime::parse_datetime;
+pub use datetime::{
+    find_datetimes, parse_datetime, parse_datetime_bytes, parse_datetime_expanded,
+    parse_datetime_expanded_bytes, parse_datetime_strict, parse_datetime_strict_bytes,
+};
+
+/// format mod
+pub mod format;
 
 /// duration mod
 pub mod duration;
-pub use duration::parse_duration;
+pub use duration::{parse_duration, parse_duration_bytes};
 
 /// offset mod
 pub mod offset;
@@ -43,11 +53,11 @@ pub mod offset;
 /// interval mod
 pub mod interval;
 
-/// fractional_duration mod
+/// `fractional_duration` mod
 pub mod fractional_duration;
 pub mod partial_date;
 pub mod partial_datetime;
 pub mod partial_time;
-pub use fractional_duration::parse_fractional_duration;
+pub use fractional_duration::{parse_fractional_duration, parse_fractional_duration_bytes};
 
-pub use offset::parse_offset;
+pub use offset::{parse_offset, parse_offset_bytes};
@@ -1,5 +1,5 @@
-use winnow::combinator::{alt, eof, opt, preceded, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{alt, opt, preceded, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
@@ -13,8 +13,17 @@ use winnow_datetime::Offset;
 /// ```rust
 /// let dt = winnow_iso8601::parse_offset("Z").unwrap();
 /// ```
-pub fn parse_offset(mut i: &str) -> Result<Offset, InputError<&str>> {
-    terminated(offset, eof).parse_next(&mut i)
+pub fn parse_offset(i: &str) -> Result<Offset, winnow_datetime::Error> {
+    offset::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses an offset from bytes like [`parse_offset`], without checking that they are UTF-8 first.
+pub fn parse_offset_bytes(i: &[u8]) -> Result<Offset, winnow_datetime::Error> {
+    offset::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 // (+...|-...)
@@ -30,11 +39,15 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset", move |input: &mut Input| {
         alt((offset_hour, offset_zulu)).parse_next(input)
     })
+    .context(StrContext::Expected(StrContextValue::CharLiteral('Z')))
+    .context(StrContext::Expected(StrContextValue::CharLiteral('+')))
+    .context(StrContext::Expected(StrContextValue::CharLiteral('-')))
+    .context(StrContext::Label("offset"))
     .parse_next(input)
 }
 
@@ -44,7 +57,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset_zulu", move |input: &mut Input| {
         literal("Z")
@@ -63,7 +76,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset_hour", move |input: &mut Input| {
         seq!((
@@ -71,7 +84,7 @@ where
             time_hour,
             opt(preceded(opt(literal(":")), time_minute))
         ))
-        .verify(|(s, h, m)| !(*s == -1 && h * 1 == 0 && (m.is_none() || m.unwrap() * 1 == 0)))
+        .verify(|(s, h, m)| !(*s == -1 && *h == 0 && (m.is_none() || m.unwrap() == 0)))
         .map(|(s, h, m)| Offset::Fixed {
             hours: s * (h as i32),
             minutes: s * (m.unwrap_or(0) as i32),
@@ -1,23 +1,25 @@
 use crate::date::{date_day_of_year, date_year, day_of_week, week_of_year};
 use core::str;
-use winnow::combinator::{alt, empty, fail, opt, preceded, trace};
-use winnow::error::ParserError;
+use winnow::combinator::{empty, fail, not, opt, preceded, trace};
+use winnow::error::{AddContext, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
-use winnow::token::literal;
+use winnow::token::{literal, one_of};
 use winnow::{seq, Parser, Result};
-use winnow_datetime::parser::{date_day, date_month};
+use winnow_datetime::gregorian::weeks_in_year;
+use winnow_datetime::parser::{date_day, date_month, furthest};
 use winnow_datetime::types::PartialDate;
 use winnow_datetime::{date_yddd_seq, date_ymd_seq, date_ywd_seq};
 
-/// Parses 2 digit week of the year within range 01-52
 /// Parses a date string as ISO 8601 week date.
+///
+/// Week 53 is only accepted in years that have one.
 // YYYY-"W"WW-D
 fn partial_date_ywd<'i, Input, Error>(input: &mut Input) -> Result<PartialDate, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date_ywd", move |input: &mut Input| {
         seq!(
@@ -29,6 +31,10 @@ where
             opt(preceded(opt(literal("-")), day_of_week))
         )
         .verify(|(_, w, d)| (w.is_some() || d.is_some()) && !(w.is_none() && d.is_some()))
+        .verify(|(y, w, _)| match (y, w) {
+            (Some(year), Some(week)) => *week <= weeks_in_year(*year),
+            _ => true,
+        })
         .map(|(year, week, day)| PartialDate::YWD { year, week, day })
         .parse_next(input)
     })
@@ -41,7 +47,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date_ymd", move |input: &mut Input| {
         seq!((
@@ -66,7 +72,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date_yddd", move |input: &mut Input| {
         seq!((
@@ -80,13 +86,34 @@ where
     .parse_next(input)
 }
 
+/// Parses a complete date string specificed as YYYYMMDD, which would otherwise be mistaken for
+/// a YYYYDDD date followed by a stray digit
+fn partial_date_ymd_basic<'i, Input, Error>(input: &mut Input) -> Result<PartialDate, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("partial_date_ymd_basic", move |input: &mut Input| {
+        seq!((date_year, date_month, date_day))
+            .map(|(year, month, day)| PartialDate::YMD {
+                year: Some(year),
+                month: Some(month),
+                day: Some(day),
+            })
+            .parse_next(input)
+    })
+    .parse_next(input)
+}
+
 /// Parses a date string specificed as YYYYMMDD
 fn partial_date_ymd_numeric<'i, Input, Error>(input: &mut Input) -> Result<PartialDate, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date_ymd_numeric", move |input: &mut Input| {
         seq!((
@@ -111,7 +138,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date_year_only", move |input: &mut Input| {
         date_year(input).map(|d| PartialDate::Year {
@@ -129,11 +156,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_date", move |input: &mut Input| {
-        alt((
+        furthest((
             partial_date_ywd,
+            partial_date_ymd_basic,
             partial_date_yddd,
             partial_date_ymd_numeric,
             partial_date_ymd,
@@ -153,7 +181,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_date", move |input: &mut Input| {
         match start_date {
@@ -188,7 +216,43 @@ where
     .parse_next(input)
 }
 
-/// Sifts through portions of end_date parses for a Date::YDDD start_date
+/// Runs `parser`, failing where it started when the input goes on with a character `continues`
+/// accepts. An alternative that stops short of what the input holds, like the day `20` of
+/// `2015-07-01`, then fails without getting anywhere, so that the error is the one of the
+/// alternative the input meant.
+pub(crate) fn not_continued<Input, Output, Error, ParseNext>(
+    mut parser: ParseNext,
+    continues: fn(char) -> bool,
+) -> impl Parser<Input, Output, Error>
+where
+    Input: StreamIsPartial + Stream,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input>,
+    ParseNext: Parser<Input, Output, Error>,
+{
+    move |input: &mut Input| {
+        let start = input.checkpoint();
+        let output = parser.parse_next(input)?;
+
+        let mut end = not(one_of(|c: <Input as Stream>::Token| continues(c.as_char())));
+
+        match Parser::<_, _, Error>::parse_next(&mut end, input) {
+            Ok(()) => Ok(output),
+            Err(e) if e.is_backtrack() => {
+                input.reset(&start);
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+// whether a character goes on with more of a date
+pub(crate) fn continues_date(c: char) -> bool {
+    c.is_ascii_digit() || c == '-' || c == 'W'
+}
+
+/// Sifts through portions of `end_date` parses for a `Date::YDDD` `start_date`
 pub(crate) fn partial_end_date_yddd<'i, Input, Error>(
     input: &mut Input,
     start_date: &PartialDate,
@@ -197,7 +261,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_date_ydd", move |input: &mut Input| {
         match start_date {
@@ -207,22 +271,31 @@ where
             } => {
                 match [start_year.is_some(), start_day.is_some()] {
                     // Case 1: Full Year-Month-Day context available
-                    [true, true] => alt((
-                        date_yddd_seq!(PartialDate::YDDD {
-                            year: date_year.map(Some),
-                            day: preceded(literal("-"), date_day_of_year).map(Some),
-                        }),
+                    [true, true] => furthest((
+                        not_continued(
+                            date_yddd_seq!(PartialDate::YDDD {
+                                year: date_year.map(Some),
+                                day: preceded(opt(literal("-")), date_day_of_year).map(Some),
+                            }),
+                            continues_date,
+                        ),
+                        not_continued(
+                            date_yddd_seq!(PartialDate::YDDD {
+                                year: opt(empty).map(|_| *start_year),
+                                day: date_day_of_year.map(Some),
+                            }),
+                            continues_date,
+                        ),
+                    ))
+                    .parse_next(input),
+                    // Case 2: Partial Year-Month (no day)
+                    [true, false] => not_continued(
                         date_yddd_seq!(PartialDate::YDDD {
                             year: opt(empty).map(|_| *start_year),
                             day: date_day_of_year.map(Some),
                         }),
-                    ))
-                    .parse_next(input),
-                    // Case 2: Partial Year-Month (no day)
-                    [true, false] => date_yddd_seq!(PartialDate::YDDD {
-                        year: opt(empty).map(|_| *start_year),
-                        day: date_day_of_year.map(Some),
-                    })
+                        continues_date,
+                    )
                     .parse_next(input),
                     _ => fail.parse_next(input),
                 }
@@ -233,7 +306,7 @@ where
     .parse_next(input)
 }
 
-/// Sifts through portions of end_date based on a Date::YMD start_date
+/// Sifts through portions of `end_date` based on a `Date::YMD` `start_date`
 fn partial_end_date_ymd<'i, Input, Error>(
     input: &mut Input,
     start_date: &PartialDate,
@@ -242,7 +315,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_date_ymd", move |input: &mut Input| {
         match start_date {
@@ -257,64 +330,82 @@ where
                     start_day.is_some(),
                 ] {
                     // Case 1: Full Year-Month-Day context available
-                    [true, true, true] => alt((
+                    [true, true, true] => furthest((
                         // Fully specified end date
-                        date_ymd_seq!(PartialDate::YMD {
-                            year: date_year.map(Some),
-                            month: preceded(literal("-"), date_month).map(Some),
-                            day: preceded(literal("-"), date_day).map(Some),
-                        }),
+                        not_continued(
+                            date_ymd_seq!(PartialDate::YMD {
+                                year: date_year.map(Some),
+                                month: preceded(opt(literal("-")), date_month).map(Some),
+                                day: preceded(opt(literal("-")), date_day).map(Some),
+                            }),
+                            continues_date,
+                        ),
                         // Default year and parse month-day
-                        date_ymd_seq!(PartialDate::YMD {
-                            year: opt(empty).map(|_| *start_year),
-                            month: date_month.map(Some),
-                            day: preceded(literal("-"), date_day).map(Some),
-                        }),
+                        not_continued(
+                            date_ymd_seq!(PartialDate::YMD {
+                                year: opt(empty).map(|_| *start_year),
+                                month: date_month.map(Some),
+                                day: preceded(opt(literal("-")), date_day).map(Some),
+                            }),
+                            continues_date,
+                        ),
                         // Default year and month, parse only day
-                        date_ymd_seq!(PartialDate::YMD {
-                            year: opt(empty).map(|_| *start_year),
-                            month: opt(empty).map(|_| *start_month),
-                            day: date_day.map(Some),
-                        }),
+                        not_continued(
+                            date_ymd_seq!(PartialDate::YMD {
+                                year: opt(empty).map(|_| *start_year),
+                                month: opt(empty).map(|_| *start_month),
+                                day: date_day.map(Some),
+                            }),
+                            continues_date,
+                        ),
                     ))
                     .parse_next(input),
 
                     // Case 2: Partial Year-Month (no day)
-                    [true, true, false] => alt((
+                    [true, true, false] => furthest((
                         // Fully specified year-month
-                        date_ymd_seq!(PartialDate::YMD {
-                            year: date_year.map(Some),
-                            month: preceded(literal("-"), date_month).map(Some),
-                            day: opt(empty).map(|_| None),
-                        }),
+                        not_continued(
+                            date_ymd_seq!(PartialDate::YMD {
+                                year: date_year.map(Some),
+                                month: preceded(opt(literal("-")), date_month).map(Some),
+                                day: opt(empty).map(|_| None),
+                            }),
+                            continues_date,
+                        ),
                         // Default year, parse month
-                        date_ymd_seq!(PartialDate::YMD {
-                            year: opt(empty).map(|_| *start_year),
-                            month: date_month.map(Some),
-                            day: opt(empty).map(|_| None),
-                        }),
+                        not_continued(
+                            date_ymd_seq!(PartialDate::YMD {
+                                year: opt(empty).map(|_| *start_year),
+                                month: date_month.map(Some),
+                                day: opt(empty).map(|_| None),
+                            }),
+                            continues_date,
+                        ),
                     ))
                     .parse_next(input),
 
                     // Case 3: Year only (no month or day)
-                    [true, false, false] => date_ymd_seq!(PartialDate::YMD {
-                        year: date_year.map(Some),
-                        month: opt(empty).map(|_| None),
-                        day: opt(empty).map(|_| None),
-                    })
+                    [true, false, false] => not_continued(
+                        date_ymd_seq!(PartialDate::YMD {
+                            year: date_year.map(Some),
+                            month: opt(empty).map(|_| None),
+                            day: opt(empty).map(|_| None),
+                        }),
+                        continues_date,
+                    )
                     .parse_next(input),
 
                     // Case 4: Invalid (no year provided)
                     [_, _, _] => fail.parse_next(input),
                 }
             }
-            _ => return Err(ParserError::from_input(input)),
+            _ => Err(ParserError::from_input(input)),
         }
     })
     .parse_next(input)
 }
 
-/// Sifts through portions of end_date based on a Date::YMD start_date
+/// Sifts through portions of `end_date` based on a `Date::YMD` `start_date`
 pub(crate) fn partial_end_date_ywd<'i, Input, Error>(
     i: &mut Input,
     start_date: &PartialDate,
@@ -323,7 +414,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_date_ywd", move |input: &mut Input| {
         match start_date {
@@ -338,49 +429,69 @@ where
                     start_day.is_some(),
                 ] {
                     // Case 1: Full start context (YWD)
-                    [true, true, true] => alt((
-                        date_ywd_seq!(PartialDate::YWD {
-                            year: date_year.map(Some),
-                            week: preceded(literal("-W"), week_of_year).map(Some),
-                            day: preceded(literal("-"), day_of_week).map(Some),
-                        }),
-                        date_ywd_seq!(PartialDate::YWD {
-                            year: opt(empty).map(|_| *start_year),
-                            week: preceded(opt(literal("W")), week_of_year).map(Some),
-                            day: preceded(literal("-"), day_of_week).map(Some),
-                        }),
-                        date_ywd_seq!(PartialDate::YWD {
-                            year: opt(empty).map(|_| *start_year),
-                            week: opt(empty).map(|_| *start_week),
-                            day: day_of_week.map(Some),
-                        }),
-                    ))
-                    .parse_next(input),
-
-                    // Case 2: Partial start (YW)
-                    [true, true, false] => {
-                        alt((
+                    [true, true, true] => furthest((
+                        not_continued(
                             date_ywd_seq!(PartialDate::YWD {
                                 year: date_year.map(Some),
-                                week: preceded(literal("-W"), week_of_year).map(Some),
-                                day: opt(empty).map(|_| None),
+                                week: preceded((opt(literal("-")), literal("W")), week_of_year)
+                                    .map(Some),
+                                day: preceded(opt(literal("-")), day_of_week).map(Some),
                             }),
+                            continues_date,
+                        ),
+                        not_continued(
                             date_ywd_seq!(PartialDate::YWD {
                                 year: opt(empty).map(|_| *start_year),
                                 week: preceded(opt(literal("W")), week_of_year).map(Some),
-                                day: opt(empty).map(|_| None),
+                                day: preceded(opt(literal("-")), day_of_week).map(Some),
+                            }),
+                            continues_date,
+                        ),
+                        not_continued(
+                            date_ywd_seq!(PartialDate::YWD {
+                                year: opt(empty).map(|_| *start_year),
+                                week: opt(empty).map(|_| *start_week),
+                                day: day_of_week.map(Some),
                             }),
+                            continues_date,
+                        ),
+                    ))
+                    .parse_next(input),
+
+                    // Case 2: Partial start (YW)
+                    [true, true, false] => {
+                        furthest((
+                            not_continued(
+                                date_ywd_seq!(PartialDate::YWD {
+                                    year: date_year.map(Some),
+                                    week: preceded((opt(literal("-")), literal("W")), week_of_year)
+                                        .map(Some),
+                                    day: opt(empty).map(|_| None),
+                                }),
+                                continues_date,
+                            ),
+                            not_continued(
+                                date_ywd_seq!(PartialDate::YWD {
+                                    year: opt(empty).map(|_| *start_year),
+                                    week: preceded(opt(literal("W")), week_of_year).map(Some),
+                                    day: opt(empty).map(|_| None),
+                                }),
+                                continues_date,
+                            ),
                         ))
                     }
                     .parse_next(input),
 
                     // Case 3: Year only
                     [true, false, false] => {
-                        date_ywd_seq!(PartialDate::YWD {
-                            year: date_year.map(Some),
-                            week: opt(empty).map(|_| None),
-                            day: opt(empty).map(|_| None),
-                        })
+                        not_continued(
+                            date_ywd_seq!(PartialDate::YWD {
+                                year: date_year.map(Some),
+                                week: opt(empty).map(|_| None),
+                                day: opt(empty).map(|_| None),
+                            }),
+                            continues_date,
+                        )
                     }
                     .parse_next(input),
 
@@ -398,6 +509,7 @@ where
 mod parsers {
     use crate::partial_date::{partial_date, partial_end_date};
     use winnow::error::InputError;
+    use winnow::Parser;
 
     use winnow_datetime::types::PartialDate;
 
@@ -425,6 +537,14 @@ mod parsers {
                 day: None
             }
         );
+        assert_eq!(
+            partial_date::<_, InputError<_>>(&mut "20150626").unwrap(),
+            PartialDate::YMD {
+                year: Some(2015),
+                month: Some(6),
+                day: Some(26)
+            }
+        );
         // YWD
         assert_eq!(
             partial_date::<_, InputError<_>>(&mut "2015-W05-6").unwrap(),
@@ -442,6 +562,17 @@ mod parsers {
                 day: None
             }
         );
+        assert_eq!(
+            partial_date::<_, InputError<_>>(&mut "2020-W53").unwrap(),
+            PartialDate::YWD {
+                year: Some(2020),
+                week: Some(53),
+                day: None
+            }
+        );
+        assert!(partial_date::<_, InputError<_>>
+            .parse("2021-W53-1")
+            .is_err());
         //Ordinal
         assert_eq!(
             partial_date::<_, InputError<_>>(&mut "2015-156").unwrap(),
@@ -1,11 +1,12 @@
-use crate::partial_date::{partial_date, partial_end_date};
+use crate::partial_date::{continues_date, not_continued, partial_date, partial_end_date};
 use crate::partial_time::{partial_end_base_time, partial_time};
 use core::str;
 use winnow::combinator::{alt, opt, preceded, trace};
-use winnow::error::ParserError;
+use winnow::error::{AddContext, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
-use winnow::{seq, Parser, Result};
+use winnow::{Parser, Result};
+use winnow_datetime::parser::furthest;
 use winnow_datetime::types::PartialDateTime;
 
 // partial date time
@@ -16,16 +17,24 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_datetime", move |input: &mut Input| {
-        seq!((
-            opt(partial_date),
-            opt(preceded(alt((literal(" "), literal("T"))), partial_time))
-        ))
-        .verify(|(d, t)| d.is_some() || t.is_some())
-        .map(|(d, t)| PartialDateTime { date: d, time: t })
-        .parse_next(input)
+        let start = input.checkpoint();
+        let date = opt(partial_date).parse_next(input)?;
+
+        // once separated from the date, the time has to be there
+        let time = match opt(alt((literal(" "), literal("T")))).parse_next(input)? {
+            Some(_) => Some(partial_time(input)?),
+            None => None,
+        };
+
+        if date.is_none() && time.is_none() {
+            input.reset(&start);
+            return Err(ParserError::from_input(input));
+        }
+
+        Ok(PartialDateTime { date, time })
     })
     .parse_next(input)
 }
@@ -38,39 +47,50 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
-    trace(
-        "partial_end_datetime",
-        move |input: &mut Input| match start_datetime {
-            PartialDateTime {
-                date: start_date,
-                time: start_time,
-            } => {
-                let mut end_date = None;
-                let mut end_time = None;
-
-                if start_date.is_none() && start_date.is_none() {
-                    return Err(ParserError::from_input(input));
-                }
-
-                if let Some(d) = start_date {
-                    end_date = partial_end_date(input, d).map(Some)?;
-                }
+    trace("partial_end_datetime", move |input: &mut Input| {
+        let PartialDateTime {
+            date: start_date,
+            time: start_time,
+        } = start_datetime;
 
-                if let Some(t) = start_time {
-                    _ = literal(" ").parse_next(input)?;
+        let separator = || alt((literal(" "), literal("T")));
 
-                    end_time = partial_end_base_time(input, t).map(Some)?;
-                }
+        let (end_date, end_time) = match (start_date, start_time) {
+            (None, None) => return Err(ParserError::from_input(input)),
+            (Some(d), None) => (Some(partial_end_date(input, d)?), None),
+            (None, Some(t)) => {
+                let _ = opt(separator()).parse_next(input)?;
 
-                Ok(PartialDateTime {
-                    date: end_date,
-                    time: end_time,
-                })
+                (None, Some(partial_end_base_time(input, t)?))
             }
-        },
-    )
+            // the date can be left out when the interval ends on the same day
+            (Some(d), Some(t)) => furthest((
+                (
+                    |input: &mut Input| partial_end_date(input, d),
+                    preceded(separator(), |input: &mut Input| {
+                        partial_end_base_time(input, t)
+                    }),
+                )
+                    .map(|(date, time)| (Some(date), Some(time))),
+                preceded(
+                    opt(literal("T")),
+                    not_continued(
+                        |input: &mut Input| partial_end_base_time(input, t),
+                        continues_date,
+                    ),
+                )
+                .map(|time| (None, Some(time))),
+            ))
+            .parse_next(input)?,
+        };
+
+        Ok(PartialDateTime {
+            date: end_date,
+            time: end_time,
+        })
+    })
     .parse_next(input)
 }
 #[cfg(test)]
@@ -78,7 +98,7 @@ mod parsers {
     use crate::partial_datetime::{partial_datetime, partial_end_datetime};
     use winnow::error::InputError;
 
-    use winnow_datetime::types::{PartialDate, PartialDateTime};
+    use winnow_datetime::types::{PartialDate, PartialDateTime, PartialTime};
 
     #[test]
     fn partial_datetime_parsing() {
@@ -333,4 +353,39 @@ mod parsers {
             }
         );
     }
+
+    #[test]
+    fn partial_time_only() {
+        let time = |hour, minute| PartialTime {
+            hour: Some(hour),
+            minute: Some(minute),
+            second: None,
+            nanosecond: None,
+            fraction_digits: 0,
+            offset: None,
+        };
+
+        assert_eq!(
+            partial_end_datetime::<_, InputError<_>>(
+                &mut " 12:30",
+                &PartialDateTime {
+                    date: None,
+                    time: Some(time(10, 0)),
+                }
+            )
+            .unwrap(),
+            PartialDateTime {
+                date: None,
+                time: Some(time(12, 30)),
+            }
+        );
+        assert!(partial_end_datetime::<_, InputError<_>>(
+            &mut " 12:30",
+            &PartialDateTime {
+                date: None,
+                time: None,
+            }
+        )
+        .is_err());
+    }
 }
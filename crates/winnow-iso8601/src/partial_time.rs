@@ -1,12 +1,14 @@
 use crate::offset::offset;
+use crate::partial_date::not_continued;
 use core::str;
 use winnow::combinator::{alt, empty, fail, opt, preceded, trace};
-use winnow::error::ParserError;
+use winnow::error::{AddContext, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::token::one_of;
 use winnow::{seq, Parser, Result};
-use winnow_datetime::parser::fraction_millisecond;
+use winnow_datetime::parser::fraction_nanosecond;
+use winnow_datetime::parser::furthest;
 use winnow_datetime::parser::time_hour;
 use winnow_datetime::parser::time_minute;
 use winnow_datetime::parser::time_second;
@@ -22,7 +24,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_time", move |input: &mut Input| {
         seq!((
@@ -44,18 +46,15 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_base_time", move |input: &mut Input| {
-        seq!(PartialTime {
-            hour: time_hour.map(Some),                        // HH
-            minute: opt(preceded(literal(":"), time_minute)), // MM
-            second: opt(preceded(literal(":"), time_second)), // SS
-            millisecond: opt(preceded(
-                alt((literal("."), literal(","))),
-                fraction_millisecond
-            )), // .mmm
-            offset: opt(offset),                              // [(Z|+...|-...)]
+        partial_time_seq!(PartialTime {
+            hour: time_hour.map(Some),                                        // HH
+            minute: opt(preceded(opt(literal(":")), time_minute)),            // MM
+            second: opt(preceded(opt(literal(":")), time_second)),            // SS
+            fraction: opt(preceded(one_of(['.', ',']), fraction_nanosecond)), // .nnn
+            offset: opt(offset),                                              // [(Z|+...|-...)]
         })
         .parse_next(input)
     })
@@ -72,7 +71,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_time", move |input: &mut Input| {
         let _ = opt(alt((literal(" "), literal("T")))).parse_next(input)?;
@@ -82,6 +81,11 @@ where
     .parse_next(input)
 }
 
+// whether a character goes on with more of a time
+fn continues_time(c: char) -> bool {
+    c.is_ascii_digit() || matches!(c, ':' | '.' | ',')
+}
+
 /// a partial time string which can be truncated depending on a partial start time
 pub(crate) fn partial_end_base_time<'a, Input, Error>(
     input: &mut Input,
@@ -91,100 +95,130 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("partial_end_base_time", move |input: &mut Input| {
         match [
             start_time.hour.is_some(),
             start_time.minute.is_some(),
             start_time.second.is_some(),
-            start_time.millisecond.is_some(),
+            start_time.nanosecond.is_some(),
         ] {
-            // Case 1: Full precision (%H:%M:%S.%ms)
-            [true, true, true, true] => alt((
-                partial_time_seq!(PartialTime {
-                    hour: time_hour.map(Some),
-                    minute: preceded(literal(":"), time_minute).map(Some),
-                    second: preceded(literal(":"), time_second).map(Some),
-                    millisecond: opt(preceded(one_of(['.', ',']), fraction_millisecond)),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: time_minute.map(Some),
-                    second: preceded(literal(":"), time_second).map(Some),
-                    millisecond: opt(preceded(one_of(['.', ',']), fraction_millisecond)),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: opt(empty).map(|_| start_time.minute),
-                    second: time_second.map(Some),
-                    millisecond: opt(preceded(one_of(['.', ',']), fraction_millisecond)),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: opt(empty).map(|_| start_time.minute),
-                    second: opt(empty).map(|_| start_time.second),
-                    millisecond: opt(preceded(one_of(['.', ',']), fraction_millisecond)),
-                    offset: opt(offset),
-                }),
+            // Case 1: Full precision (%H:%M:%S.%f)
+            [true, true, true, true] => furthest((
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: time_hour.map(Some),
+                        minute: preceded(opt(literal(":")), time_minute).map(Some),
+                        second: preceded(opt(literal(":")), time_second).map(Some),
+                        fraction: opt(preceded(one_of(['.', ',']), fraction_nanosecond)),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: time_minute.map(Some),
+                        second: preceded(opt(literal(":")), time_second).map(Some),
+                        fraction: opt(preceded(one_of(['.', ',']), fraction_nanosecond)),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: opt(empty).map(|_| start_time.minute),
+                        second: time_second.map(Some),
+                        fraction: opt(preceded(one_of(['.', ',']), fraction_nanosecond)),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: opt(empty).map(|_| start_time.minute),
+                        second: opt(empty).map(|_| start_time.second),
+                        fraction: preceded(one_of(['.', ',']), fraction_nanosecond).map(Some),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
             ))
             .parse_next(input),
-            // Case 2: HH:MM:SS (no milliseconds)
-            [true, true, true, false] => alt((
-                partial_time_seq!(PartialTime {
-                    hour: time_hour.map(Some),
-                    minute: preceded(literal(":"), time_minute).map(Some),
-                    second: preceded(literal(":"), time_second).map(Some),
-                    millisecond: opt(empty).map(|_| None),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: time_minute.map(Some),
-                    second: preceded(literal(":"), time_second).map(Some),
-                    millisecond: opt(empty).map(|_| None),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: opt(empty).map(|_| start_time.minute),
-                    second: time_second.map(Some),
-                    millisecond: opt(empty).map(|_| None),
-                    offset: opt(offset),
-                }),
+            // Case 2: HH:MM:SS (no fraction)
+            [true, true, true, false] => furthest((
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: time_hour.map(Some),
+                        minute: preceded(opt(literal(":")), time_minute).map(Some),
+                        second: preceded(opt(literal(":")), time_second).map(Some),
+                        fraction: opt(empty).map(|_| None),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: time_minute.map(Some),
+                        second: preceded(opt(literal(":")), time_second).map(Some),
+                        fraction: opt(empty).map(|_| None),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: opt(empty).map(|_| start_time.minute),
+                        second: time_second.map(Some),
+                        fraction: opt(empty).map(|_| None),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+            ))
+            .parse_next(input),
+
+            // Case 3: HH:MM (no seconds or fraction)
+            [true, true, false, false] => furthest((
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: time_hour.map(Some),
+                        minute: preceded(opt(literal(":")), time_minute).map(Some),
+                        second: opt(empty).map(|_| None),
+                        fraction: opt(empty).map(|_| None),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
+                not_continued(
+                    partial_time_seq!(PartialTime {
+                        hour: opt(empty).map(|_| start_time.hour),
+                        minute: time_minute.map(Some),
+                        second: opt(empty).map(|_| None),
+                        fraction: opt(empty).map(|_| None),
+                        offset: opt(offset),
+                    }),
+                    continues_time,
+                ),
             ))
             .parse_next(input),
 
-            // Case 3: HH:MM (no seconds or milliseconds)
-            [true, true, false, false] => alt((
+            // Case 4: HH only (no minutes, seconds, or fraction)
+            [true, false, false, false] => not_continued(
                 partial_time_seq!(PartialTime {
                     hour: time_hour.map(Some),
-                    minute: preceded(literal(":"), time_minute).map(Some),
-                    second: opt(empty).map(|_| None),
-                    millisecond: opt(empty).map(|_| None),
-                    offset: opt(offset),
-                }),
-                partial_time_seq!(PartialTime {
-                    hour: opt(empty).map(|_| start_time.hour),
-                    minute: time_minute.map(Some),
+                    minute: opt(empty).map(|_| None),
                     second: opt(empty).map(|_| None),
-                    millisecond: opt(empty).map(|_| None),
+                    fraction: opt(empty).map(|_| None),
                     offset: opt(offset),
                 }),
-            ))
-            .parse_next(input),
-
-            // Case 4: HH only (no minutes, seconds, or milliseconds)
-            [true, false, false, false] => partial_time_seq!(PartialTime {
-                hour: time_hour.map(Some),
-                minute: opt(empty).map(|_| None),
-                second: opt(empty).map(|_| None),
-                millisecond: opt(empty).map(|_| None),
-                offset: opt(offset),
-            })
+                continues_time,
+            )
             .parse_next(input),
 
             // Case 5: Invalid (no hour provided)
@@ -209,7 +243,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: Some(30),
-                millisecond: None,
+                nanosecond: None,
+                fraction_digits: 0,
                 offset: None,
             }
         );
@@ -219,7 +254,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: None,
-                millisecond: None,
+                nanosecond: None,
+                fraction_digits: 0,
                 offset: None,
             }
         );
@@ -229,7 +265,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: Some(30),
-                millisecond: Some(123),
+                nanosecond: Some(123_000_000),
+                fraction_digits: 3,
                 offset: None,
             }
         );
@@ -244,7 +281,8 @@ mod parsers {
                     hour: Some(12),
                     minute: Some(1),
                     second: Some(29),
-                    millisecond: None,
+                    nanosecond: None,
+                    fraction_digits: 0,
                     offset: None,
                 }
             )
@@ -253,7 +291,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: Some(30),
-                millisecond: None,
+                nanosecond: None,
+                fraction_digits: 0,
                 offset: None,
             }
         );
@@ -264,7 +303,8 @@ mod parsers {
                     hour: Some(12),
                     minute: Some(0),
                     second: None,
-                    millisecond: None,
+                    nanosecond: None,
+                    fraction_digits: 0,
                     offset: None,
                 }
             )
@@ -273,7 +313,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: None,
-                millisecond: None,
+                nanosecond: None,
+                fraction_digits: 0,
                 offset: None,
             }
         );
@@ -284,7 +325,8 @@ mod parsers {
                     hour: Some(12),
                     minute: Some(1),
                     second: Some(30),
-                    millisecond: Some(122),
+                    nanosecond: Some(122_000_000),
+                    fraction_digits: 3,
                     offset: None,
                 }
             )
@@ -293,7 +335,8 @@ mod parsers {
                 hour: Some(12),
                 minute: Some(1),
                 second: Some(30),
-                millisecond: Some(123),
+                nanosecond: Some(123_000_000),
+                fraction_digits: 3,
                 offset: None,
             }
         );
@@ -1,10 +1,10 @@
 use crate::offset::offset;
-use winnow::combinator::{eof, opt, preceded, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{opt, preceded, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::{literal, one_of};
 use winnow::{seq, Parser, Result};
-use winnow_datetime::parser::{fraction_millisecond, time_hour, time_minute, time_second};
+use winnow_datetime::parser::{fraction_nanosecond, time_hour, time_minute, time_second};
 use winnow_datetime::Time;
 
 /// Parses a time string.
@@ -15,8 +15,17 @@ use winnow_datetime::Time;
 /// ```rust
 /// let time = winnow_iso8601::parse_time("21:56:42").unwrap();
 /// ```
-pub fn parse_time(mut i: &str) -> Result<Time, InputError<&str>> {
-    terminated(time, eof).parse_next(&mut i)
+pub fn parse_time(i: &str) -> Result<Time, winnow_datetime::Error> {
+    time::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a time from bytes like [`parse_time`], without checking that they are UTF-8 first.
+pub fn parse_time_bytes(i: &[u8]) -> Result<Time, winnow_datetime::Error> {
+    time::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a time with an optional preceding 'T'.
@@ -34,7 +43,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time", move |input: &mut Input| {
         seq!((
@@ -44,6 +53,7 @@ where
         .map(|r| r.0)
         .parse_next(input)
     })
+    .context(StrContext::Label("time"))
     .parse_next(input)
 }
 
@@ -56,51 +66,54 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("base_time", move |input: &mut Input| {
         let hour = time_hour(input)?;
 
-        let msms = opt(time_minute_second_millisecond).parse_next(input)?; // MM:[SS][.(m*)]
+        let msms = opt(time_minute_second_fraction).parse_next(input)?; // MM:[SS][.(m*)]
 
         let offset = opt(offset).parse_next(input)?;
 
-        let (minute, second, millisecond) = msms.unwrap_or((0, None, None));
+        let (minute, second, fraction) = msms.unwrap_or((0, None, None));
+        let (nanosecond, fraction_digits) = fraction.unwrap_or((0, 0));
 
         Ok(Time {
-            hour,                                  // HH
-            minute,                                // MM
-            second: second.unwrap_or(0),           // [SS]
-            millisecond: millisecond.unwrap_or(0), // [.(m*)]
-            offset,                                // [(Z|+...|-...)]
+            hour,                        // HH
+            minute,                      // MM
+            second: second.unwrap_or(0), // [SS]
+            nanosecond,                  // [.(m*)]
+            fraction_digits,
+            offset, // [(Z|+...|-...)]
             time_zone: None,
             calendar: None,
         })
     })
+    .context(StrContext::Label("time"))
     .parse_next(input)
 }
 
+/// Minute, second and fraction of a second (nanoseconds and digit count) of a time string
+type TimeMinuteSecondFraction = (u32, Option<u32>, Option<(u32, u8)>);
+
 /// Parses secondary portion of a time string.
-pub(crate) fn time_minute_second_millisecond<'i, Input, Error>(
+pub(crate) fn time_minute_second_fraction<'i, Input, Error>(
     input: &mut Input,
-) -> std::result::Result<(u32, Option<u32>, Option<u32>), Error>
+) -> std::result::Result<TimeMinuteSecondFraction, Error>
 where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
-    trace(
-        "time_minute_second_millisecond",
-        move |input: &mut Input| {
-            seq!(
-                preceded(opt(literal(":")), time_minute),
-                opt(preceded(opt(literal(":")), time_second)),
-                opt(preceded(one_of(b",."), fraction_millisecond))
-            )
-            .parse_next(input)
-        },
-    )
+    trace("time_minute_second_fraction", move |input: &mut Input| {
+        seq!(
+            preceded(opt(literal(":")), time_minute),
+            opt(preceded(opt(literal(":")), time_second)),
+            opt(preceded(one_of(b",."), fraction_nanosecond))
+        )
+        .parse_next(input)
+    })
     .parse_next(input)
 }
 
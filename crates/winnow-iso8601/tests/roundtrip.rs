@@ -0,0 +1,78 @@
+//! Checks that everything the parsers accept survives being printed and parsed again,
+//! `parse(print(parse(x))) == parse(x)`.
+
+use proptest::prelude::*;
+use winnow_iso8601::format::{
+    format_date, format_datetime, format_duration, format_interval, format_time, Notation,
+};
+use winnow_iso8601::interval::parse_interval;
+use winnow_iso8601::{parse_date, parse_datetime, parse_duration, parse_time};
+
+const DATE: &str = "[0-9]{4}(-?(0[1-9]|1[0-2])-?(0[1-9]|[12][0-9]|3[01])|-?W(0[1-9]|[1-4][0-9]|5[0-3])-?[1-7]|-?(00[1-9]|0[1-9][0-9]|[12][0-9]{2}|3[0-5][0-9]|36[0-6]))";
+const TIME: &str = "([01][0-9]|2[0-3])(:?[0-5][0-9](:?[0-5][0-9]([.,][0-9]{1,9})?)?)?(Z|[+-](0[0-9]|1[0-4])(:?[0-5][0-9])?)?";
+const DURATION: &str = "P([0-9]{1,4}W|([0-9]{1,4}Y)?([0-9]{1,4}M)?([0-9]{1,4}D)?(T([0-9]{1,4}H)?([0-9]{1,4}M)?([0-9]{1,4}([.,][0-9]{1,6})?S)?)?)";
+
+fn notation() -> impl Strategy<Value = Notation> {
+    prop_oneof![Just(Notation::Basic), Just(Notation::Extended)]
+}
+
+fn datetime() -> impl Strategy<Value = String> {
+    (DATE, TIME).prop_map(|(d, t)| format!("{}T{}", d, t))
+}
+
+fn interval() -> impl Strategy<Value = String> {
+    (
+        "(R[0-9]{0,3}/)?",
+        prop_oneof![
+            (datetime(), datetime()).prop_map(|(s, e)| format!("{}/{}", s, e)),
+            (datetime(), DURATION).prop_map(|(s, d)| format!("{}/{}", s, d)),
+            (DURATION, datetime()).prop_map(|(d, e)| format!("{}/{}", d, e)),
+            DURATION.prop_map(|d| d.to_string()),
+        ],
+    )
+        .prop_map(|(r, i)| format!("{}{}", r, i))
+}
+
+macro_rules! assert_roundtrip {
+    ($input:expr, $parse:path, |$value:ident| $print:expr) => {
+        if let Ok($value) = $parse(&$input) {
+            let printed = $print.unwrap();
+
+            prop_assert_eq!(
+                $parse(&printed),
+                Ok($value),
+                "{:?} was printed as {:?}",
+                $input,
+                printed
+            );
+        }
+    };
+}
+
+proptest! {
+    #[test]
+    fn date(input in DATE, notation in notation()) {
+        assert_roundtrip!(input, parse_date, |v| format_date(&v, notation));
+    }
+
+    #[test]
+    fn time(input in TIME, notation in notation()) {
+        assert_roundtrip!(input, parse_time, |v| format_time(&v, notation));
+    }
+
+    #[test]
+    fn datetime_(input in datetime(), notation in notation()) {
+        assert_roundtrip!(input, parse_datetime, |v| format_datetime(&v, notation));
+    }
+
+    #[test]
+    fn duration(sign in "[+-]?", input in DURATION) {
+        let input = format!("{}{}", sign, input);
+        assert_roundtrip!(input, parse_duration, |v| format_duration(&v));
+    }
+
+    #[test]
+    fn interval_(input in interval(), notation in notation()) {
+        assert_roundtrip!(input, parse_interval, |v| format_interval(&v, notation));
+    }
+}
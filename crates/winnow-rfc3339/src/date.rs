@@ -1,11 +1,11 @@
+use winnow::combinator::preceded;
 use winnow::combinator::trace;
-use winnow::combinator::{eof, preceded, terminated};
-use winnow::error::{InputError, ParserError};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser};
 use winnow_datetime::parser::date_month;
-use winnow_datetime::parser::{date_day, digit_4};
+use winnow_datetime::parser::{date_day, digit_4, existing_date};
 use winnow_datetime::{date_ymd_seq, Date};
 
 /// Parses a date string.
@@ -16,8 +16,38 @@ use winnow_datetime::{date_ymd_seq, Date};
 /// use winnow::Result;
 /// let date = winnow_rfc3339::parse_date("2015-11-02").unwrap();
 /// ```
-pub fn parse_date(mut i: &str) -> Result<Date, InputError<&str>> {
-    terminated(date, eof).parse_next(&mut i)
+pub fn parse_date(i: &str) -> Result<Date, winnow_datetime::Error> {
+    date::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date from bytes like [`parse_date`], without checking that they are UTF-8 first.
+pub fn parse_date_bytes(i: &[u8]) -> Result<Date, winnow_datetime::Error> {
+    date::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date string, rejecting dates that don't exist in the calendar such as `2015-02-29`.
+///
+/// ## Example
+///
+/// ```rust
+/// assert!(winnow_rfc3339::parse_date_strict("2016-02-29").is_ok());
+/// assert!(winnow_rfc3339::parse_date_strict("2015-02-29").is_err());
+/// ```
+pub fn parse_date_strict(i: &str) -> Result<Date, winnow_datetime::Error> {
+    date_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a date from bytes like [`parse_date_strict`], without checking that they are UTF-8 first.
+pub fn parse_date_strict_bytes(i: &[u8]) -> Result<Date, winnow_datetime::Error> {
+    date_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a date
@@ -33,9 +63,28 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
-    trace("parse_date", move |input: &mut Input| date_ymd(input)).parse_next(input)
+    trace("parse_date", move |input: &mut Input| date_ymd(input))
+        .context(StrContext::Label("date"))
+        .parse_next(input)
+}
+
+/// Parses a date like [`date()`], failing when the date doesn't exist in the calendar.
+///
+/// See [`Date::validate`] for the checks applied.
+pub fn date_strict<'i, Input, Error>(input: &mut Input) -> Result<Date, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("date_strict", move |input: &mut Input| {
+        existing_date(date).parse_next(input)
+    })
+    .context(StrContext::Label("date"))
+    .parse_next(input)
 }
 
 /// Date separator -
@@ -44,11 +93,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_sep", move |input: &mut Input| {
         literal("-").parse_next(input).map(|_| '-')
     })
+    .context(StrContext::Expected(StrContextValue::CharLiteral('-')))
     .parse_next(input)
 }
 
@@ -59,12 +109,16 @@ where
     Input: StreamIsPartial + Stream,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_year", move |input: &mut Input| match digit_4(input) {
         Ok(d) => Ok(d as i32),
         Err(e) => Err(e),
     })
+    .context(StrContext::Expected(StrContextValue::Description(
+        "four digit year",
+    )))
+    .context(StrContext::Label("year"))
     .parse_next(input)
 }
 
@@ -75,7 +129,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("date_ymd", move |input: &mut Input| {
         date_ymd_seq!(Date::YMD {
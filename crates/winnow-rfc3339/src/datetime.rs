@@ -1,10 +1,11 @@
-use crate::date::date;
+use crate::date::{date, date_strict};
 use crate::time::time;
-use winnow::combinator::{alt, eof, separated_pair, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{alt, separated_pair, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{Parser, Result};
+use winnow_datetime::scan::Matches;
 use winnow_datetime::DateTime;
 
 /// Parses a datetime string.
@@ -18,8 +19,56 @@ use winnow_datetime::DateTime;
 /// ```rust
 /// let dt = winnow_rfc3339::parse_datetime("2015-11-03T21:56:00Z").unwrap();
 /// ```
-pub fn parse_datetime(mut i: &str) -> Result<DateTime, InputError<&str>> {
-    terminated(datetime, eof).parse_next(&mut i)
+pub fn parse_datetime(i: &str) -> Result<DateTime, winnow_datetime::Error> {
+    datetime::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a datetime from bytes like [`parse_datetime`], without checking that they are UTF-8
+/// first.
+pub fn parse_datetime_bytes(i: &[u8]) -> Result<DateTime, winnow_datetime::Error> {
+    datetime::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a datetime string, rejecting dates that don't exist in the calendar.
+///
+/// ## Example
+///
+/// ```rust
+/// assert!(winnow_rfc3339::parse_datetime_strict("2015-02-29T21:56:00Z").is_err());
+/// ```
+pub fn parse_datetime_strict(i: &str) -> Result<DateTime, winnow_datetime::Error> {
+    datetime_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a datetime from bytes like [`parse_datetime_strict`], without checking that they are
+/// UTF-8 first.
+pub fn parse_datetime_strict_bytes(i: &[u8]) -> Result<DateTime, winnow_datetime::Error> {
+    datetime_strict::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Finds the datetimes in a text, such as the timestamps of a log line, along with the byte span
+/// of each. Dates that don't exist in the calendar aren't matched, and neither are datetimes
+/// inside a longer word, see [`winnow_datetime::scan`].
+///
+/// ## Example
+///
+/// ```rust
+/// let text = "sent 2015-06-26T16:43:23+02:00, retried 2015-06-26T16:44:00Z";
+/// let found: Vec<_> = winnow_rfc3339::find_datetimes(text).collect();
+///
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(&text[found[0].range()], "2015-06-26T16:43:23+02:00");
+/// ```
+pub fn find_datetimes(text: &str) -> Matches<'_, DateTime> {
+    Matches::new(text, datetime_strict)
 }
 
 /// Parses a datetime string.
@@ -31,13 +80,31 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("parse_datetime", move |input: &mut Input| {
         separated_pair(date, alt((literal("T"), literal("t"))), time)
             .map(|(d, t)| DateTime { date: d, time: t })
             .parse_next(input)
     })
+    .context(StrContext::Label("datetime"))
+    .parse_next(input)
+}
+
+/// Parses a datetime like [`datetime()`], failing when the date doesn't exist in the calendar.
+pub fn datetime_strict<'i, Input, Error>(input: &mut Input) -> Result<DateTime, Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'i str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("datetime_strict", move |input: &mut Input| {
+        separated_pair(date_strict, alt((literal("T"), literal("t"))), time)
+            .map(|(d, t)| DateTime { date: d, time: t })
+            .parse_next(input)
+    })
+    .context(StrContext::Label("datetime"))
     .parse_next(input)
 }
 
@@ -64,4 +131,27 @@ mod parsers {
                 .is_err()
         );
     }
+
+    #[test]
+    fn bytes_match_str() {
+        use crate::{parse_datetime, parse_datetime_bytes};
+
+        for input in ["2015-06-26T16:43:23.123+02:00", "2015-06-26T16:43:23+2:00"] {
+            assert_eq!(
+                parse_datetime_bytes(input.as_bytes()),
+                parse_datetime(input)
+            );
+        }
+        assert!(parse_datetime_bytes("2015-06-26T16:43:23.123+02:00".as_bytes()).is_ok());
+        assert!(parse_datetime_bytes(b"2015-06-26T16:43:\xff").is_err());
+    }
+
+    #[test]
+    fn find_datetimes_at_word_boundaries() {
+        use crate::find_datetimes;
+
+        let text = "x2015-06-26T16:43:23Z 2015-02-29T16:43:23Z id_2015-06-26T16:43:23Z (2015-06-26t16:43:23.5z)";
+        let found: Vec<_> = find_datetimes(text).map(|m| &text[m.range()]).collect();
+        assert_eq!(found, ["2015-06-26t16:43:23.5z"]);
+    }
 }
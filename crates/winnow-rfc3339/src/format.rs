@@ -0,0 +1,131 @@
+//! Writers emitting values in the RFC 3339 profile.
+//!
+//! RFC 3339 only knows calendar dates with four digit years, always uses the extended notation and
+//! requires an offset on every time, so writing a value outside of that profile is an error rather
+//! than producing something a RFC 3339 parser would reject.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use winnow_rfc3339::format::format_datetime;
+//!
+//! let dt = winnow_rfc3339::parse_datetime("2015-06-26T16:43:23.100+00:00").unwrap();
+//!
+//! assert_eq!(format_datetime(&dt).unwrap(), "2015-06-26T16:43:23.100Z");
+//! ```
+
+use core::fmt::Write;
+pub use winnow_datetime::writer::FormatError;
+use winnow_datetime::writer::{write_four_digit_year, write_nanosecond, Notation};
+use winnow_datetime::{Date, DateTime, Offset, Time};
+
+/// Writes a `full-date`, such as `2015-06-26`.
+pub fn write_date<W: Write>(w: &mut W, date: &Date) -> Result<(), FormatError> {
+    match date {
+        Date::YMD { year, month, day } => {
+            write_four_digit_year(w, *year)?;
+            write!(w, "-{:02}-{:02}", month, day)?;
+
+            Ok(())
+        }
+        _ => Err(FormatError::UnsupportedDate),
+    }
+}
+
+/// Writes a `time-offset`, `Z` for UTC and `-00:00` for an unknown local offset.
+pub fn write_offset<W: Write>(w: &mut W, offset: &Offset) -> Result<(), FormatError> {
+    winnow_datetime::writer::write_offset(w, offset, Notation::Extended)?;
+
+    Ok(())
+}
+
+/// Writes a `full-time`, such as `16:43:23.123+02:00`.
+pub fn write_time<W: Write>(w: &mut W, time: &Time) -> Result<(), FormatError> {
+    let offset = time.offset.as_ref().ok_or(FormatError::MissingOffset)?;
+
+    write!(w, "{:02}:{:02}:{:02}", time.hour, time.minute, time.second)?;
+    write_nanosecond(w, time.nanosecond, time.fraction_digits)?;
+    write_offset(w, offset)
+}
+
+/// Writes a `date-time`, such as `2015-06-26T16:43:23+02:00`.
+pub fn write_datetime<W: Write>(w: &mut W, datetime: &DateTime) -> Result<(), FormatError> {
+    write_date(w, &datetime.date)?;
+    w.write_str("T")?;
+    write_time(w, &datetime.time)
+}
+
+/// Formats a date into a new `String`, see [`write_date`].
+pub fn format_date(date: &Date) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_date(&mut s, date)?;
+    Ok(s)
+}
+
+/// Formats an offset into a new `String`, see [`write_offset`].
+pub fn format_offset(offset: &Offset) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_offset(&mut s, offset)?;
+    Ok(s)
+}
+
+/// Formats a time into a new `String`, see [`write_time`].
+pub fn format_time(time: &Time) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_time(&mut s, time)?;
+    Ok(s)
+}
+
+/// Formats a datetime into a new `String`, see [`write_datetime`].
+pub fn format_datetime(datetime: &DateTime) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_datetime(&mut s, datetime)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_datetime, parse_offset};
+
+    #[test]
+    fn round_trips() {
+        for s in [
+            "2015-06-26T16:43:23+02:00",
+            "2015-06-26T16:43:23.123Z",
+            "0001-01-01T00:00:00-00:00",
+        ] {
+            assert_eq!(format_datetime(&parse_datetime(s).unwrap()).unwrap(), s);
+        }
+
+        assert_eq!(
+            format_offset(&parse_offset("+00:00").unwrap()).unwrap(),
+            "Z"
+        );
+    }
+
+    #[test]
+    fn rejects_values_outside_of_the_profile() {
+        let mut dt = parse_datetime("2015-06-26T16:43:23Z").unwrap();
+
+        dt.time.offset = None;
+        assert_eq!(format_datetime(&dt), Err(FormatError::MissingOffset));
+
+        assert_eq!(
+            format_date(&Date::Week {
+                year: 2015,
+                week: 5,
+                day: 6
+            }),
+            Err(FormatError::UnsupportedDate)
+        );
+        assert_eq!(
+            format_date(&Date::YMD {
+                year: -333,
+                month: 7,
+                day: 11
+            }),
+            Err(FormatError::YearOutOfRange(-333))
+        );
+    }
+}
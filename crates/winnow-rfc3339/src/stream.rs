@@ -0,0 +1,406 @@
+//! Parsing datetimes from input that arrives in chunks, such as a socket or a file read one buffer
+//! at a time.
+//!
+//! Datetimes are separated by ASCII whitespace, a newline or a space. [`StreamParser`] buffers the
+//! chunks it is given and tells a datetime that needs more input apart from an invalid one, which
+//! works with any byte source including async ones, while [`parse_reader`] drives it from an
+//! [`io::Read`](std::io::Read).
+//!
+//! ```rust
+//! use winnow_rfc3339::StreamParser;
+//!
+//! let mut parser = StreamParser::new();
+//!
+//! parser.push(b"2015-06-26T16:43:23+02:00\n2015-06-2");
+//! assert!(parser.next_datetime().unwrap().is_some());
+//! assert_eq!(parser.next_datetime(), Ok(None));
+//!
+//! parser.push(b"7T09:00:00Z\n");
+//! assert!(parser.next_datetime().unwrap().is_some());
+//! ```
+
+use crate::datetime::{datetime, parse_datetime_bytes};
+use std::io::{self, Read};
+use std::vec::Vec;
+use std::{error, fmt};
+use winnow::error::{ContextError, ErrMode};
+use winnow::stream::Stream;
+use winnow_datetime::{DateTime, PartialInput};
+
+// the longest a datetime or an invalid token is buffered for before it is rejected, far longer
+// than any datetime short of one with an absurd number of fraction digits
+const MAX_TOKEN: usize = 256;
+
+/// Parses datetimes out of chunks of input as they arrive.
+#[derive(Debug, Clone, Default)]
+pub struct StreamParser {
+    buffer: Vec<u8>,
+    // where the input that isn't parsed yet starts in the buffer
+    cursor: usize,
+    // whether the rest of a rejected token is still to be dropped
+    skipping: bool,
+}
+
+impl StreamParser {
+    /// Creates a parser with nothing buffered
+    pub fn new() -> StreamParser {
+        StreamParser::default()
+    }
+
+    /// Adds the next chunk of input
+    pub fn push(&mut self, chunk: &[u8]) {
+        // parsed input is only dropped once it's at least half of the buffer, so that moving the
+        // rest to the front stays linear in the length of the input overall
+        if self.cursor > 0 && self.cursor >= self.buffer.len() - self.cursor {
+            self.buffer.drain(..self.cursor);
+            self.cursor = 0;
+        }
+
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// The input pushed but not parsed yet
+    pub fn buffered(&self) -> &[u8] {
+        &self.buffer[self.cursor..]
+    }
+
+    /// Parses the next datetime, returning `Ok(None)` when the buffered input is empty or only
+    /// the start of a datetime and more is needed.
+    ///
+    /// A datetime has to be followed by whitespace, so one at the end of the buffered input is
+    /// only returned once what follows it has been pushed, or by [`StreamParser::finish`].
+    ///
+    /// An invalid token is reported once the whitespace after it has been pushed, so that the
+    /// error holds all of it however the input was chunked, and is then dropped so that parsing
+    /// can go on with the next one. A token is rejected without waiting for its end once 256
+    /// bytes of it are buffered, and the error then holds those first 256 bytes.
+    pub fn next_datetime(&mut self) -> Result<Option<DateTime>, winnow_datetime::Error> {
+        self.next(false)
+    }
+
+    /// Parses the next datetime once there is no more input, failing if what is left is only the
+    /// start of one.
+    pub fn finish(&mut self) -> Result<Option<DateTime>, winnow_datetime::Error> {
+        let next = self.next(true);
+        self.skipping = false;
+
+        next
+    }
+
+    fn next(&mut self, eof: bool) -> Result<Option<DateTime>, winnow_datetime::Error> {
+        if self.skipping {
+            let end = self.buffered().iter().position(u8::is_ascii_whitespace);
+            self.skipping = end.is_none();
+            self.cursor += end.unwrap_or(self.buffered().len());
+        }
+
+        self.cursor += self
+            .buffered()
+            .iter()
+            .position(|b| !b.is_ascii_whitespace())
+            .unwrap_or(self.buffered().len());
+
+        if self.buffered().is_empty() {
+            return Ok(None);
+        }
+
+        let token_end = self.buffered().iter().position(u8::is_ascii_whitespace);
+
+        let mut input = PartialInput::new(self.buffered());
+        match datetime::<_, ErrMode<ContextError>>(&mut input) {
+            Ok(datetime) => {
+                let parsed = self.buffered().len() - input.eof_offset();
+
+                match token_end {
+                    Some(end) if end == parsed => {}
+                    None if parsed == self.buffered().len() && eof => {}
+                    None if parsed == self.buffered().len() => return Ok(None),
+                    _ => return self.reject(token_end, eof),
+                }
+
+                self.cursor += parsed;
+
+                Ok(Some(datetime))
+            }
+            Err(ErrMode::Incomplete(_)) if !eof && self.buffered().len() < MAX_TOKEN => Ok(None),
+            Err(_) => self.reject(token_end, eof),
+        }
+    }
+
+    // drops the buffered token ending at `end`, or the rest of it later on when its end isn't
+    // buffered yet, parsing it as complete input to describe why it isn't a datetime
+    fn reject(
+        &mut self,
+        end: Option<usize>,
+        eof: bool,
+    ) -> Result<Option<DateTime>, winnow_datetime::Error> {
+        let end = match end {
+            Some(end) => end,
+            None if eof => self.buffered().len(),
+            None if self.buffered().len() >= MAX_TOKEN => {
+                self.skipping = true;
+                MAX_TOKEN
+            }
+            None => return Ok(None),
+        };
+
+        let rejected = parse_datetime_bytes(&self.buffered()[..end]);
+        self.cursor += end;
+
+        rejected.map(Some)
+    }
+}
+
+/// Failure to read a datetime with [`parse_reader`]
+#[derive(Debug)]
+pub enum StreamError {
+    /// the input isn't a datetime
+    Invalid(winnow_datetime::Error),
+    /// reading the input failed
+    Io(io::Error),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Invalid(err) => write!(f, "{}", err),
+            StreamError::Io(err) => write!(f, "failed to read datetimes: {}", err),
+        }
+    }
+}
+
+impl error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            StreamError::Invalid(err) => Some(err),
+            StreamError::Io(err) => Some(err),
+        }
+    }
+}
+
+impl From<winnow_datetime::Error> for StreamError {
+    fn from(err: winnow_datetime::Error) -> Self {
+        StreamError::Invalid(err)
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+/// Iterator over the datetimes of a reader, from [`parse_reader`]
+#[derive(Debug)]
+pub struct ReadDateTimes<R> {
+    reader: R,
+    parser: StreamParser,
+    eof: bool,
+}
+
+impl<R: Read> Iterator for ReadDateTimes<R> {
+    type Item = Result<DateTime, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = [0; 1024];
+
+        loop {
+            if self.eof {
+                return self.parser.finish().map_err(StreamError::from).transpose();
+            }
+
+            if let Some(datetime) = self.parser.next_datetime().transpose() {
+                return Some(datetime.map_err(StreamError::from));
+            }
+
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.eof = true,
+                Ok(read) => self.parser.push(&chunk[..read]),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Some(Err(err.into())),
+            }
+        }
+    }
+}
+
+/// Parses whitespace separated datetimes from a reader, reading more input whenever the buffered
+/// input ends in the middle of one.
+///
+/// An invalid datetime gives a [`StreamError::Invalid`] and iteration goes on with the next one.
+///
+/// ## Example
+///
+/// ```rust
+/// let log = "2015-06-26T16:43:23Z\n2015-06-26T16:43:24.5+02:00\n".as_bytes();
+///
+/// let datetimes = winnow_rfc3339::parse_reader(log)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(datetimes.len(), 2);
+/// ```
+pub fn parse_reader<R: Read>(reader: R) -> ReadDateTimes<R> {
+    ReadDateTimes {
+        reader,
+        parser: StreamParser::new(),
+        eof: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_datetime;
+
+    const LOG: &str = "2015-06-26T16:43:23Z 2015-06-26T16:43:23.123456+02:00\n\
+                       2016-12-31T23:59:60z\n";
+
+    #[test]
+    fn chunked_input() {
+        let expected = LOG
+            .split_whitespace()
+            .map(|dt| parse_datetime(dt).unwrap())
+            .collect::<Vec<_>>();
+
+        for size in 1..LOG.len() {
+            let mut parser = StreamParser::new();
+            let mut parsed = Vec::new();
+
+            for chunk in LOG.as_bytes().chunks(size) {
+                parser.push(chunk);
+
+                while let Some(datetime) = parser.next_datetime().unwrap() {
+                    parsed.push(datetime);
+                }
+            }
+
+            assert_eq!(parser.finish(), Ok(None));
+            assert_eq!(parsed, expected, "chunks of {} bytes", size);
+        }
+    }
+
+    #[test]
+    fn chunked_invalid_input() {
+        let input = "2015-06-26T16:43:23Z not-a-date 2015-13-26T16:43:23Z\n2015-06-26T16:4";
+
+        for size in 1..input.len() {
+            let mut parser = StreamParser::new();
+            let mut errors = Vec::new();
+
+            for chunk in input.as_bytes().chunks(size) {
+                parser.push(chunk);
+
+                while let Some(result) = parser.next_datetime().transpose() {
+                    errors.extend(result.err());
+                }
+            }
+            errors.extend(parser.finish().err());
+
+            let errors = errors
+                .iter()
+                .map(|err| (err.input(), err.offset()))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                errors,
+                [
+                    ("not-a-date", 0),
+                    ("2015-13-26T16:43:23Z", 5),
+                    ("2015-06-26T16:4", 14)
+                ],
+                "chunks of {} bytes",
+                size
+            );
+        }
+    }
+
+    #[test]
+    fn incomplete_and_invalid_input() {
+        let mut parser = StreamParser::new();
+
+        parser.push(b"2015-06-26T16:43:23.5");
+        assert_eq!(parser.next_datetime(), Ok(None));
+        assert_eq!(parser.buffered(), b"2015-06-26T16:43:23.5");
+
+        parser.push(b" 2015-13-26T16:43:23Z 2015-06-26T16:43:23Z");
+        let err = parser.next_datetime().unwrap_err();
+        assert_eq!(err.input(), "2015-06-26T16:43:23.5");
+        assert_eq!(err.offset(), 21);
+
+        let err = parser.next_datetime().unwrap_err();
+        assert_eq!(err.component(), Some("month"));
+        // what follows the last datetime isn't known yet
+        assert_eq!(parser.next_datetime(), Ok(None));
+
+        parser.push(b"\ngarb");
+        assert!(parser.next_datetime().unwrap().is_some());
+        assert_eq!(parser.next_datetime(), Ok(None));
+        parser.push(b"age 2015-06-26T16:43:23Zjunk 2015-06-26T16:43:23Z");
+        let err = parser.next_datetime().unwrap_err();
+        assert_eq!(err.input(), "garbage");
+        assert_eq!(err.offset(), 0);
+        let err = parser.next_datetime().unwrap_err();
+        assert_eq!(err.input(), "2015-06-26T16:43:23Zjunk");
+        assert_eq!(err.offset(), 20);
+
+        parser.push(b"\n2015-06-26T16:4");
+        assert!(parser.next_datetime().unwrap().is_some());
+        assert_eq!(parser.next_datetime(), Ok(None));
+        assert_eq!(parser.finish().unwrap_err().input(), "2015-06-26T16:4");
+        assert_eq!(parser.finish(), Ok(None));
+    }
+
+    #[test]
+    fn datetime_at_the_end() {
+        let mut parser = StreamParser::new();
+
+        parser.push(b"2015-06-26T16:43:23Z");
+        assert_eq!(parser.next_datetime(), Ok(None));
+        assert!(parser.finish().unwrap().is_some());
+        assert_eq!(parser.finish(), Ok(None));
+    }
+
+    #[test]
+    fn long_invalid_token() {
+        let mut parser = StreamParser::new();
+
+        for _ in 0..MAX_TOKEN - 1 {
+            parser.push(b"x");
+            assert_eq!(parser.next_datetime(), Ok(None));
+        }
+
+        parser.push(b"x");
+        let err = parser.next_datetime().unwrap_err();
+        assert_eq!(err.input().len(), MAX_TOKEN);
+
+        for _ in 0..10_000 {
+            parser.push(b"x");
+            assert_eq!(parser.next_datetime(), Ok(None));
+            assert!(parser.buffer.len() <= 2);
+        }
+
+        parser.push(b" 2015-06-26T16:43:23Z");
+        assert!(parser.finish().unwrap().is_some());
+    }
+
+    struct OneByte<'a>(&'a [u8]);
+
+    impl Read for OneByte<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            (&mut self.0).take(1).read(buf)
+        }
+    }
+
+    #[test]
+    fn reader() {
+        let input = "2015-06-26T16:43:23Z\nnot-a-date\n2015-06-26T16:43:2";
+        let results = parse_reader(OneByte(input.as_bytes())).collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(
+            matches!(&results[1], Err(StreamError::Invalid(err)) if err.input() == "not-a-date")
+        );
+        assert!(
+            matches!(&results[2], Err(StreamError::Invalid(err)) if err.component() == Some("second"))
+        );
+    }
+}
@@ -1,14 +1,14 @@
 use crate::offset::offset;
 use winnow::combinator::empty;
+use winnow::combinator::opt;
 use winnow::combinator::preceded;
 use winnow::combinator::trace;
-use winnow::combinator::{eof, opt, terminated};
-use winnow::error::{InputError, ParserError};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::token::one_of;
 use winnow::{seq, Parser, Result};
-use winnow_datetime::parser::fraction_millisecond;
+use winnow_datetime::parser::fraction_nanosecond;
 use winnow_datetime::parser::time_hour;
 use winnow_datetime::parser::time_minute;
 use winnow_datetime::parser::time_second;
@@ -28,8 +28,17 @@ use winnow_datetime::{time_seq, Time};
 /// ```rust
 /// let time = winnow_rfc3339::parse_time("21:56:42Z").unwrap();
 /// ```
-pub fn parse_time(mut i: &str) -> Result<Time, InputError<&str>> {
-    terminated(time, eof).parse_next(&mut i)
+pub fn parse_time(i: &str) -> Result<Time, winnow_datetime::Error> {
+    time::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a time from bytes like [`parse_time`], without checking that they are UTF-8 first.
+pub fn parse_time_bytes(i: &[u8]) -> Result<Time, winnow_datetime::Error> {
+    time::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a time string with an optional preceding 'T'.
@@ -41,20 +50,22 @@ where
     Input: StreamIsPartial + Stream + Compare<&'i str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("parse_time", move |input: &mut Input| {
         time_seq!(Time {
             hour: time_hour,                             // HH
             minute: preceded(literal(":"), time_minute), // MM
             second: preceded(literal(":"), time_second), // [SS]
-            millisecond: opt(preceded(one_of(b",."), fraction_millisecond)).map(|d| d.unwrap_or(0)), // [.(m*)]
-            offset: offset.map(|o| Some(o)), // [(Z|+...|-...)]
+            fraction: opt(preceded(one_of(b",."), fraction_nanosecond))
+                .map(|d| d.unwrap_or((0, 0))), // [.(m*)]
+            offset: offset.map(Some),                    // [(Z|+...|-...)]
             time_zone: empty.map(|_| None),
             calendar: empty.map(|_| None),
         })
         .parse_next(input)
     })
+    .context(StrContext::Label("time"))
     .parse_next(input)
 }
 
@@ -2,7 +2,9 @@ use winnow_datetime::Date;
 use winnow_datetime::DateTime;
 use winnow_datetime::Offset;
 use winnow_datetime::Time;
-use winnow_rfc3339::{parse_date, parse_datetime, parse_time};
+use winnow_rfc3339::{
+    parse_date, parse_date_strict, parse_datetime, parse_datetime_strict, parse_time,
+};
 
 #[test]
 fn test_date() {
@@ -27,7 +29,8 @@ fn test_millisecond() {
                 hour: 16,
                 minute: 43,
                 second: 0,
-                millisecond: i,
+                nanosecond: i * 1_000_000,
+                fraction_digits: 3,
                 offset: Some(Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -46,7 +49,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -63,7 +67,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 120,
+            nanosecond: 120_000_000,
+            fraction_digits: 2,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -80,7 +85,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -97,7 +103,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 432,
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -114,7 +121,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 11,
-            millisecond: 432,
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -131,7 +139,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -148,7 +157,8 @@ fn test_millisecond() {
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 123,
+            nanosecond: 123_450_000,
+            fraction_digits: 5,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -165,7 +175,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -182,7 +193,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -199,7 +211,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 5,
                 minutes: 0,
@@ -212,6 +225,16 @@ fn test_millisecond() {
     );
 }
 
+#[test]
+fn test_nanosecond() {
+    let time = parse_time("16:43:00.123456789Z").unwrap();
+    assert_eq!((time.nanosecond, time.fraction_digits), (123_456_789, 9));
+    assert_eq!(time.millisecond(), 123);
+
+    let time = parse_time("16:43:00.1234567891Z").unwrap();
+    assert_eq!((time.nanosecond, time.fraction_digits), (123_456_789, 10));
+}
+
 #[test]
 fn test_time() {
     assert!(parse_time("16:43:16").is_err());
@@ -231,7 +254,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -248,7 +272,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -265,7 +290,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::Fixed {
                 hours: 5,
                 minutes: 0,
@@ -294,7 +320,8 @@ fn test_datetime_correct() {
                 hour: 12,
                 minute: 0,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 1,
                     minutes: 0,
@@ -318,7 +345,8 @@ fn test_datetime_correct() {
                 hour: 18,
                 minute: 30,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 2,
                     minutes: 0,
@@ -342,7 +370,8 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -366,7 +395,8 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -393,7 +423,8 @@ fn lower_case_separators() {
                 hour: 18,
                 minute: 30,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 0,
                     minutes: 0,
@@ -405,3 +436,39 @@ fn lower_case_separators() {
         })
     );
 }
+
+#[test]
+fn error_points_at_component() {
+    let e = parse_date("2015-13-01").unwrap_err();
+    assert_eq!(e.offset(), 5);
+    assert_eq!(e.component(), Some("month"));
+    assert_eq!(
+        e.to_string(),
+        "invalid month at byte 5, expected two digit month from 01 to 12\n  2015-13-01\n       ^"
+    );
+
+    let e = parse_datetime("2015-06-26T16:43:23").unwrap_err();
+    assert_eq!(e.offset(), 19);
+    assert_eq!(e.component(), Some("offset"));
+    assert_eq!(
+        e.to_string(),
+        "invalid offset at byte 19, expected `Z` or `+` or `-`\n  2015-06-26T16:43:23\n                     ^"
+    );
+}
+
+#[test]
+fn strict_rejects_missing_days() {
+    assert!(parse_date("2015-02-29").is_ok());
+    assert!(parse_date_strict("2016-02-29").is_ok());
+    assert!(parse_datetime_strict("2016-02-29T12:00:00Z").is_ok());
+
+    let e = parse_date_strict("2015-02-29").unwrap_err();
+    assert_eq!(e.offset(), 8);
+    assert_eq!(e.component(), Some("day"));
+    assert_eq!(
+        e.to_string(),
+        "invalid day at byte 8, expected a day that the month has\n  2015-02-29\n          ^"
+    );
+    assert!(parse_date_strict("2015-04-31").is_err());
+    assert!(parse_datetime_strict("2015-02-29T12:00:00Z").is_err());
+}
@@ -0,0 +1,45 @@
+//! Checks that everything the parsers accept survives being printed and parsed again,
+//! `parse(print(parse(x))) == parse(x)`.
+
+use proptest::prelude::*;
+use winnow_rfc3339::format::{format_date, format_datetime, format_time};
+use winnow_rfc3339::{parse_date, parse_datetime, parse_time};
+
+const DATE: &str = "[0-9]{4}-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])";
+const TIME: &str =
+    "([01][0-9]|2[0-3]):[0-5][0-9]:[0-5][0-9](\\.[0-9]{1,9})?(Z|z|[+-](0[0-9]|1[0-4]):[0-5][0-9])";
+
+macro_rules! assert_roundtrip {
+    ($input:expr, $parse:path, |$value:ident| $print:expr) => {
+        if let Ok($value) = $parse(&$input) {
+            let printed = $print.unwrap();
+
+            prop_assert_eq!(
+                $parse(&printed),
+                Ok($value),
+                "{:?} was printed as {:?}",
+                $input,
+                printed
+            );
+        }
+    };
+}
+
+proptest! {
+    #[test]
+    fn date(input in DATE) {
+        assert_roundtrip!(input, parse_date, |v| format_date(&v));
+    }
+
+    #[test]
+    fn time(input in TIME) {
+        assert_roundtrip!(input, parse_time, |v| format_time(&v));
+    }
+
+    #[test]
+    fn datetime(date in DATE, separator in "[Tt]", time in TIME) {
+        let input = format!("{}{}{}", date, separator, time);
+
+        assert_roundtrip!(input, parse_datetime, |v| format_datetime(&v));
+    }
+}
@@ -1,5 +1,5 @@
-use winnow::combinator::{alt, eof, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{alt, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{Parser, Result};
@@ -16,8 +16,18 @@ use winnow_datetime::types::Calendar;
 /// ```rust
 /// let dt = winnow_rfc9557::parse_time_zone("America/Los_Angeles").unwrap();
 /// ```
-pub fn parse_calendar(mut i: &str) -> Result<Calendar, InputError<&str>> {
-    terminated(calendar, eof).parse_next(&mut i)
+pub fn parse_calendar(i: &str) -> Result<Calendar, winnow_datetime::Error> {
+    calendar::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a calendar from bytes like [`parse_calendar`], without checking that they are UTF-8
+/// first.
+pub fn parse_calendar_bytes(i: &[u8]) -> Result<Calendar, winnow_datetime::Error> {
+    calendar::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a time zone string.
@@ -28,7 +38,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("calendar", move |input: &mut Input| {
         alt((
@@ -58,5 +68,6 @@ where
         })
         .parse_next(input)
     })
+    .context(StrContext::Label("calendar"))
     .parse_next(input)
 }
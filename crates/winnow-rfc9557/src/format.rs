@@ -0,0 +1,191 @@
+//! Writers emitting values in the RFC 9557 profile.
+//!
+//! RFC 9557 extends RFC 3339, so the same rules apply to the date, time and offset, followed by
+//! the bracketed `[time-zone]` and `[u-ca=calendar]` suffixes when the time carries them.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use winnow_rfc9557::format::format_datetime;
+//!
+//! let dt = winnow_rfc9557::parse_datetime("2022-07-08T00:14:07+01:00[!Europe/Paris]").unwrap();
+//!
+//! assert_eq!(format_datetime(&dt).unwrap(), "2022-07-08T00:14:07+01:00[!Europe/Paris]");
+//! ```
+
+use core::fmt::Write;
+pub use winnow_datetime::writer::FormatError;
+use winnow_datetime::writer::{
+    write_calendar_suffix, write_expanded_year, write_four_digit_year, write_nanosecond,
+    write_numeric_offset, write_time_zone_suffix, Notation,
+};
+use winnow_datetime::{Date, DateTime, Offset, Time};
+
+/// Writes a `full-date`, such as `2015-06-26`.
+pub fn write_date<W: Write>(w: &mut W, date: &Date) -> Result<(), FormatError> {
+    match date {
+        Date::YMD { year, month, day } => {
+            write_four_digit_year(w, *year)?;
+            write!(w, "-{:02}-{:02}", month, day)?;
+
+            Ok(())
+        }
+        _ => Err(FormatError::UnsupportedDate),
+    }
+}
+
+/// Writes a `full-date` with an expanded year of `4 + extra_digits` digits, such as
+/// `+002015-06-26`, as read by [`parse_date_expanded`](crate::parse_date_expanded).
+pub fn write_date_expanded<W: Write>(
+    w: &mut W,
+    date: &Date,
+    extra_digits: u8,
+) -> Result<(), FormatError> {
+    match date {
+        Date::YMD { year, month, day } => {
+            write_expanded_year(w, *year, extra_digits)?;
+            write!(w, "-{:02}-{:02}", month, day)?;
+
+            Ok(())
+        }
+        _ => Err(FormatError::UnsupportedDate),
+    }
+}
+
+/// Writes a `time-offset`.
+///
+/// RFC 9557 redefines `Z` to mean that the local offset is unknown, so
+/// [`Offset::LocalUnknown`] is written as `Z` while a known UTC offset is written as `+00:00`.
+pub fn write_offset<W: Write>(w: &mut W, offset: &Offset) -> Result<(), FormatError> {
+    match offset {
+        Offset::LocalUnknown { .. } => w.write_str("Z")?,
+        Offset::Fixed { .. } => write_numeric_offset(w, offset, Notation::Extended)?,
+    }
+
+    Ok(())
+}
+
+/// Writes a `full-time` followed by its suffixes, such as `16:43:23+02:00[Europe/Paris]`.
+pub fn write_time<W: Write>(w: &mut W, time: &Time) -> Result<(), FormatError> {
+    let offset = time.offset.as_ref().ok_or(FormatError::MissingOffset)?;
+
+    write!(w, "{:02}:{:02}:{:02}", time.hour, time.minute, time.second)?;
+    write_nanosecond(w, time.nanosecond, time.fraction_digits)?;
+    write_offset(w, offset)?;
+
+    if let Some(time_zone) = &time.time_zone {
+        write_time_zone_suffix(w, time_zone)?;
+    }
+
+    if let Some(calendar) = &time.calendar {
+        write_calendar_suffix(w, calendar)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a `date-time` followed by its suffixes.
+pub fn write_datetime<W: Write>(w: &mut W, datetime: &DateTime) -> Result<(), FormatError> {
+    write_date(w, &datetime.date)?;
+    w.write_str("T")?;
+    write_time(w, &datetime.time)
+}
+
+/// Writes a `date-time` with an expanded year of `4 + extra_digits` digits, followed by its
+/// suffixes.
+pub fn write_datetime_expanded<W: Write>(
+    w: &mut W,
+    datetime: &DateTime,
+    extra_digits: u8,
+) -> Result<(), FormatError> {
+    write_date_expanded(w, &datetime.date, extra_digits)?;
+    w.write_str("T")?;
+    write_time(w, &datetime.time)
+}
+
+/// Formats a date into a new `String`, see [`write_date`].
+pub fn format_date(date: &Date) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_date(&mut s, date)?;
+    Ok(s)
+}
+
+/// Formats a date into a new `String`, see [`write_date_expanded`].
+pub fn format_date_expanded(date: &Date, extra_digits: u8) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_date_expanded(&mut s, date, extra_digits)?;
+    Ok(s)
+}
+
+/// Formats an offset into a new `String`, see [`write_offset`].
+pub fn format_offset(offset: &Offset) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_offset(&mut s, offset)?;
+    Ok(s)
+}
+
+/// Formats a time into a new `String`, see [`write_time`].
+pub fn format_time(time: &Time) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_time(&mut s, time)?;
+    Ok(s)
+}
+
+/// Formats a datetime into a new `String`, see [`write_datetime`].
+pub fn format_datetime(datetime: &DateTime) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_datetime(&mut s, datetime)?;
+    Ok(s)
+}
+
+/// Formats a datetime into a new `String`, see [`write_datetime_expanded`].
+pub fn format_datetime_expanded(
+    datetime: &DateTime,
+    extra_digits: u8,
+) -> Result<String, FormatError> {
+    let mut s = String::new();
+    write_datetime_expanded(&mut s, datetime, extra_digits)?;
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_datetime;
+
+    #[test]
+    fn round_trips() {
+        for s in [
+            "2015-06-26T16:43:23+02:00",
+            "2022-07-08T00:14:07Z[Europe/London][u-ca=gregory]",
+            "2022-07-08T00:14:07.500+01:00[!+01:00][!u-ca=japanese]",
+        ] {
+            assert_eq!(format_datetime(&parse_datetime(s).unwrap()).unwrap(), s);
+        }
+    }
+
+    #[test]
+    fn requires_an_offset() {
+        let mut dt = parse_datetime("2022-07-08T00:14:07Z[Europe/London]").unwrap();
+
+        dt.time.offset = None;
+        assert_eq!(format_datetime(&dt), Err(FormatError::MissingOffset));
+    }
+
+    #[test]
+    fn zulu_is_an_unknown_offset() {
+        assert_eq!(
+            format_offset(&Offset::LocalUnknown { critical: false }).unwrap(),
+            "Z"
+        );
+        assert_eq!(
+            format_offset(&Offset::Fixed {
+                hours: 0,
+                minutes: 0,
+                critical: false
+            })
+            .unwrap(),
+            "+00:00"
+        );
+    }
+}
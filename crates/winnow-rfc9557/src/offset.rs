@@ -1,5 +1,5 @@
-use winnow::combinator::{alt, eof, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{alt, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
@@ -18,8 +18,17 @@ use winnow_datetime::Offset;
 /// ```rust
 /// let dt = winnow_rfc9557::parse_offset("Z").unwrap();
 /// ```
-pub fn parse_offset(mut i: &str) -> Result<Offset, InputError<&str>> {
-    terminated(offset, eof).parse_next(&mut i)
+pub fn parse_offset(i: &str) -> Result<Offset, winnow_datetime::Error> {
+    offset::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses an offset from bytes like [`parse_offset`], without checking that they are UTF-8 first.
+pub fn parse_offset_bytes(i: &[u8]) -> Result<Offset, winnow_datetime::Error> {
+    offset::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses an offset string.
@@ -31,11 +40,15 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset_hour", move |input: &mut Input| {
         alt((offset_hour, offset_zulu)).parse_next(input)
     })
+    .context(StrContext::Expected(StrContextValue::CharLiteral('Z')))
+    .context(StrContext::Expected(StrContextValue::CharLiteral('+')))
+    .context(StrContext::Expected(StrContextValue::CharLiteral('-')))
+    .context(StrContext::Label("offset"))
     .parse_next(input)
 }
 
@@ -45,7 +58,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset_zulu", move |input: &mut Input| {
         alt((literal("Z"), literal("z")))
@@ -61,7 +74,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("offset_hour", move |input: &mut Input| {
         let s: i32 = sign.parse_next(input)?;
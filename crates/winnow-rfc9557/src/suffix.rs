@@ -1,11 +1,12 @@
 use crate::calendar::calendar;
 use crate::time_zone::time_zone;
-use winnow::combinator::opt;
 use winnow::combinator::trace;
-use winnow::error::ParserError;
+use winnow::combinator::{opt, peek};
+use winnow::error::{AddContext, ParserError, StrContext, StrContextValue};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
+use winnow_datetime::parser::furthest;
 use winnow_datetime::types::Calendar;
 use winnow_datetime::types::TimeZone;
 use winnow_datetime::Offset;
@@ -15,7 +16,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("suffix_start", move |input: &mut Input| {
         seq!(literal("["), opt(literal("!")),)
@@ -30,11 +31,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
-    trace("suffix_start", move |input: &mut Input| {
+    trace("suffix_end", move |input: &mut Input| {
         literal("]").map(|_| ()).parse_next(input)
     })
+    .context(StrContext::Expected(StrContextValue::CharLiteral(']')))
     .parse_next(input)
 }
 
@@ -43,13 +45,13 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("suffix_time_zone", move |input: &mut Input| {
         seq!(
             suffix_start,
             time_zone,
-            _: suffix_end,
+            _: suffix_end.context(StrContext::Label("time zone")),
         )
         .map(|(c, tz): (bool, TimeZone)| match tz {
             TimeZone::Named { zone: mut tz } => {
@@ -83,14 +85,14 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("suffix_calendar", move |input: &mut Input| {
         seq!(
             suffix_start,
             _: literal("u-ca="),
             calendar,
-            _: suffix_end,
+            _: suffix_end.context(StrContext::Label("calendar")),
         )
         .map(|(critical, mut c): (bool, Calendar)| {
             c.critical = critical;
@@ -101,12 +103,55 @@ where
     .parse_next(input)
 }
 
+/// Fails where a suffix left after the time zone and calendars stops parsing, such as the end of
+/// `[Europe/Paris` missing its `]`, rather than leaving the whole suffix as remaining input.
+pub fn suffix_left<'a, Input, Error>(input: &mut Input) -> Result<(), Error>
+where
+    Input: StreamIsPartial + Stream + Compare<&'a str>,
+    <Input as Stream>::Slice: AsBStr,
+    <Input as Stream>::Token: AsChar + Clone,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
+{
+    trace("suffix_left", move |input: &mut Input| {
+        if opt(peek(literal("["))).parse_next(input)?.is_none() {
+            return Ok(());
+        }
+
+        let start = input.checkpoint();
+        furthest((suffix_time_zone.map(|_| ()), suffix_calendar.map(|_| ()))).parse_next(input)?;
+
+        // a whole suffix out of place is left to the caller
+        input.reset(&start);
+        Ok(())
+    })
+    .parse_next(input)
+}
+
 #[cfg(test)]
 mod test {
     use crate::parse_time;
     use winnow_datetime::types::NamedTimeZone;
     use winnow_datetime::{Calendar, Offset, Time, TimeZone};
 
+    #[test]
+    fn unclosed_suffix() {
+        let e = parse_time("16:43:16Z[Europe/Paris").unwrap_err();
+        assert_eq!(e.offset(), 22);
+        assert_eq!(e.component(), Some("time zone"));
+        assert_eq!(
+            e.to_string(),
+            "invalid time zone at byte 22, expected `]`\n  16:43:16Z[Europe/Paris\n                        ^"
+        );
+
+        let e = parse_time("16:43:16Z[Europe/Paris][u-ca=gregory").unwrap_err();
+        assert_eq!(e.offset(), 36);
+        assert_eq!(e.component(), Some("calendar"));
+
+        let e = crate::parse_datetime("2015-06-26T16:43:16Z[Europe/Paris").unwrap_err();
+        assert_eq!(e.offset(), 33);
+        assert_eq!(e.component(), Some("time zone"));
+    }
+
     #[test]
     fn test_suffix_critical_named_timezone() {
         let result = parse_time("16:43:16Z[!America/New_York]").unwrap();
@@ -116,7 +161,8 @@ mod test {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: Some(TimeZone::Named {
                     zone: NamedTimeZone {
@@ -138,7 +184,8 @@ mod test {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: Some(TimeZone::Named {
                     zone: NamedTimeZone {
@@ -166,7 +213,8 @@ mod test {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: Some(TimeZone::Named {
                     zone: NamedTimeZone {
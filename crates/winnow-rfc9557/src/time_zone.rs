@@ -1,8 +1,8 @@
 use crate::offset::offset;
 use winnow::ascii::alpha1;
 use winnow::combinator::repeat;
-use winnow::combinator::{alt, eof, terminated, trace};
-use winnow::error::{InputError, ParserError};
+use winnow::combinator::{alt, trace};
+use winnow::error::{AddContext, ContextError, ParserError, StrContext};
 use winnow::stream::{AsBStr, AsChar, Compare, Stream, StreamIsPartial};
 use winnow::token::literal;
 use winnow::{seq, Parser, Result};
@@ -19,8 +19,18 @@ use winnow_datetime::types::{NamedTimeZone, TimeZone};
 /// ```rust
 /// let dt = winnow_rfc9557::parse_time_zone("America/Los_Angeles").unwrap();
 /// ```
-pub fn parse_time_zone(mut i: &str) -> Result<TimeZone, InputError<&str>> {
-    terminated(time_zone, eof).parse_next(&mut i)
+pub fn parse_time_zone(i: &str) -> Result<TimeZone, winnow_datetime::Error> {
+    time_zone::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
+}
+
+/// Parses a time zone from bytes like [`parse_time_zone`], without checking that they are UTF-8
+/// first.
+pub fn parse_time_zone_bytes(i: &[u8]) -> Result<TimeZone, winnow_datetime::Error> {
+    time_zone::<_, ContextError>
+        .parse(i)
+        .map_err(winnow_datetime::Error::from)
 }
 
 /// Parses a time zone string.
@@ -31,11 +41,12 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_zone", move |input: &mut Input| {
         alt((named_time_zone, fixed_time_zone)).parse_next(input)
     })
+    .context(StrContext::Label("time zone"))
     .parse_next(input)
 }
 
@@ -47,7 +58,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_zone", move |input: &mut Input| {
         offset
@@ -65,7 +76,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_zone", move |input: &mut Input| {
         seq!(
@@ -96,7 +107,7 @@ where
     Input: StreamIsPartial + Stream + Compare<&'a str>,
     <Input as Stream>::Slice: AsBStr,
     <Input as Stream>::Token: AsChar + Clone,
-    Error: ParserError<Input>,
+    Error: ParserError<Input> + AddContext<Input, StrContext>,
 {
     trace("time_zone_part", move |input: &mut Input| {
         seq!(alpha1, repeat(0.., seq!(literal("_"), alpha1,)))
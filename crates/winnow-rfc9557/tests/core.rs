@@ -2,7 +2,11 @@ use winnow_datetime::Date;
 use winnow_datetime::DateTime;
 use winnow_datetime::Offset;
 use winnow_datetime::Time;
-use winnow_rfc9557::{parse_date, parse_datetime, parse_time};
+use winnow_rfc9557::format::{format_date_expanded, format_datetime_expanded};
+use winnow_rfc9557::{
+    parse_date, parse_date_expanded, parse_date_strict, parse_datetime, parse_datetime_expanded,
+    parse_datetime_strict, parse_time,
+};
 
 #[test]
 fn test_date() {
@@ -27,7 +31,8 @@ fn test_millisecond() {
                 hour: 16,
                 minute: 43,
                 second: 0,
-                millisecond: i,
+                nanosecond: i * 1_000_000,
+                fraction_digits: 3,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: None,
                 calendar: None,
@@ -42,7 +47,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -55,7 +61,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 120,
+            nanosecond: 120_000_000,
+            fraction_digits: 2,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -68,7 +75,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -81,7 +89,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 432,
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -94,7 +103,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 11,
-            millisecond: 432,
+            nanosecond: 432_100_000,
+            fraction_digits: 4,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -107,7 +117,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
+            nanosecond: 100_000_000,
+            fraction_digits: 1,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -120,7 +131,8 @@ fn test_millisecond() {
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 123,
+            nanosecond: 123_450_000,
+            fraction_digits: 5,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -133,7 +145,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -146,7 +159,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -163,7 +177,8 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
+            nanosecond: 123_000_000,
+            fraction_digits: 3,
             offset: Some(Offset::Fixed {
                 hours: 5,
                 minutes: 0,
@@ -195,7 +210,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::LocalUnknown { critical: false }),
             time_zone: None,
             calendar: None,
@@ -208,7 +224,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::Fixed {
                 hours: 0,
                 minutes: 0,
@@ -225,7 +242,8 @@ fn test_time_with_offset() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
+            nanosecond: 0,
+            fraction_digits: 0,
             offset: Some(Offset::Fixed {
                 hours: 5,
                 minutes: 0,
@@ -254,7 +272,8 @@ fn test_datetime_correct() {
                 hour: 12,
                 minute: 0,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 1,
                     minutes: 0,
@@ -278,7 +297,8 @@ fn test_datetime_correct() {
                 hour: 18,
                 minute: 30,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::Fixed {
                     hours: 2,
                     minutes: 0,
@@ -302,7 +322,8 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: None,
                 calendar: None,
@@ -322,7 +343,8 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: None,
                 calendar: None,
@@ -345,7 +367,8 @@ fn lower_case_separators() {
                 hour: 18,
                 minute: 30,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
+                fraction_digits: 0,
                 offset: Some(Offset::LocalUnknown { critical: false }),
                 time_zone: None,
                 calendar: None,
@@ -353,3 +376,39 @@ fn lower_case_separators() {
         })
     );
 }
+
+#[test]
+fn strict_rejects_missing_days() {
+    assert!(parse_date("2015-02-29").is_ok());
+    assert!(parse_date_strict("2016-02-29").is_ok());
+    assert!(parse_datetime_strict("2016-02-29T12:00:00Z").is_ok());
+
+    let e = parse_date_strict("2015-02-29").unwrap_err();
+    assert_eq!(e.offset(), 8);
+    assert_eq!(e.component(), Some("day"));
+    assert_eq!(
+        e.to_string(),
+        "invalid day at byte 8, expected a day that the month has\n  2015-02-29\n          ^"
+    );
+    assert!(parse_date_strict("2015-04-31").is_err());
+    assert!(parse_datetime_strict("2015-02-29T12:00:00Z").is_err());
+}
+
+#[test]
+fn expanded_years() {
+    let date = parse_date_expanded("-000001-01-01", 2).unwrap();
+    assert_eq!(
+        date,
+        Date::YMD {
+            year: -1,
+            month: 1,
+            day: 1
+        }
+    );
+    assert_eq!(format_date_expanded(&date, 2).unwrap(), "-000001-01-01");
+    assert!(parse_date_expanded("2015-06-26", 2).is_err());
+
+    let s = "+275760-09-13T00:00:00Z[u-ca=iso8601]";
+    let dt = parse_datetime_expanded(s, 2).unwrap();
+    assert_eq!(format_datetime_expanded(&dt, 2).unwrap(), s);
+}
@@ -0,0 +1,48 @@
+//! Checks that everything the parsers accept survives being printed and parsed again,
+//! `parse(print(parse(x))) == parse(x)`.
+
+use proptest::prelude::*;
+use winnow_rfc9557::format::{format_date, format_datetime, format_time};
+use winnow_rfc9557::{parse_date, parse_datetime, parse_time};
+
+const DATE: &str = "[0-9]{4}-(0[1-9]|1[0-2])-(0[1-9]|[12][0-9]|3[01])";
+const TIME: &str =
+    "([01][0-9]|2[0-3]):[0-5][0-9]:[0-5][0-9](\\.[0-9]{1,9})?(Z|z|[+-](0[0-9]|1[0-4]):[0-5][0-9])";
+const SUFFIXES: &str = "(\\[!?(Europe/Paris|America/Argentina/Buenos_Aires|Etc/GMT\\+5|[+-](0[0-9]|1[0-4]):[0-5][0-9])\\])?(\\[!?u-ca=(gregory|japanese|islamic-civil)\\])?";
+
+macro_rules! assert_roundtrip {
+    ($input:expr, $parse:path, |$value:ident| $print:expr) => {
+        if let Ok($value) = $parse(&$input) {
+            let printed = $print.unwrap();
+
+            prop_assert_eq!(
+                $parse(&printed),
+                Ok($value),
+                "{:?} was printed as {:?}",
+                $input,
+                printed
+            );
+        }
+    };
+}
+
+proptest! {
+    #[test]
+    fn date(input in DATE) {
+        assert_roundtrip!(input, parse_date, |v| format_date(&v));
+    }
+
+    #[test]
+    fn time(time in TIME, suffixes in SUFFIXES) {
+        let input = format!("{}{}", time, suffixes);
+
+        assert_roundtrip!(input, parse_time, |v| format_time(&v));
+    }
+
+    #[test]
+    fn datetime(date in DATE, separator in "[Tt]", time in TIME, suffixes in SUFFIXES) {
+        let input = format!("{}{}{}{}", date, separator, time, suffixes);
+
+        assert_roundtrip!(input, parse_datetime, |v| format_datetime(&v));
+    }
+}
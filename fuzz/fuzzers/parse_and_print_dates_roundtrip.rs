@@ -1,21 +1,63 @@
 #![no_main]
 
-#[macro_use] extern crate libfuzzer_sys;
-extern crate iso8601;
+use libfuzzer_sys::fuzz_target;
+use winnow_iso8601::format::Notation;
 
+// parse(print(parse(x))) == parse(x) for every input the parser accepts
 macro_rules! roundtrip {
-    ($data:ident, $method:ident) => {
-        if let Ok(x) = iso8601::$method($data) {
-            let x_printed = format!("{}", x);
-            let _parse_again = iso8601::$method(&x_printed);
+    ($data:ident, $parse:path, |$value:ident| $print:expr) => {
+        if let Ok($value) = $parse($data) {
+            let printed = $print.expect("parsed values can be printed");
+
+            assert_eq!(
+                $parse(&printed),
+                Ok($value),
+                "{:?} was printed as {:?}",
+                $data,
+                printed
+            );
         }
     };
 }
 
-fuzz_target!(|data| {
+fuzz_target!(|data: &[u8]| {
     if let Ok(data) = std::str::from_utf8(data) {
-        roundtrip!(data, date);
-        roundtrip!(data, time);
-        roundtrip!(data, datetime);
+        for notation in [Notation::Basic, Notation::Extended] {
+            roundtrip!(data, winnow_iso8601::parse_date, |v| {
+                winnow_iso8601::format::format_date(&v, notation)
+            });
+            roundtrip!(data, winnow_iso8601::parse_time, |v| {
+                winnow_iso8601::format::format_time(&v, notation)
+            });
+            roundtrip!(data, winnow_iso8601::parse_datetime, |v| {
+                winnow_iso8601::format::format_datetime(&v, notation)
+            });
+            roundtrip!(data, winnow_iso8601::interval::parse_interval, |v| {
+                winnow_iso8601::format::format_interval(&v, notation)
+            });
+        }
+        roundtrip!(data, winnow_iso8601::parse_duration, |v| {
+            winnow_iso8601::format::format_duration(&v)
+        });
+
+        roundtrip!(data, winnow_rfc3339::parse_date, |v| {
+            winnow_rfc3339::format::format_date(&v)
+        });
+        roundtrip!(data, winnow_rfc3339::parse_time, |v| {
+            winnow_rfc3339::format::format_time(&v)
+        });
+        roundtrip!(data, winnow_rfc3339::parse_datetime, |v| {
+            winnow_rfc3339::format::format_datetime(&v)
+        });
+
+        roundtrip!(data, winnow_rfc9557::parse_date, |v| {
+            winnow_rfc9557::format::format_date(&v)
+        });
+        roundtrip!(data, winnow_rfc9557::parse_time, |v| {
+            winnow_rfc9557::format::format_time(&v)
+        });
+        roundtrip!(data, winnow_rfc9557::parse_datetime, |v| {
+            winnow_rfc9557::format::format_datetime(&v)
+        });
     }
 });
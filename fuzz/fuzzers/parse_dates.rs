@@ -1,12 +1,21 @@
 #![no_main]
 
-#[macro_use] extern crate libfuzzer_sys;
-extern crate iso8601;
+use libfuzzer_sys::fuzz_target;
 
-fuzz_target!(|data| {
+fuzz_target!(|data: &[u8]| {
     if let Ok(data) = std::str::from_utf8(data) {
-        let _ = iso8601::date(data);
-        let _ = iso8601::time(data);
-        let _ = iso8601::datetime(data);
+        let _ = winnow_iso8601::parse_date(data);
+        let _ = winnow_iso8601::parse_time(data);
+        let _ = winnow_iso8601::parse_datetime(data);
+        let _ = winnow_iso8601::parse_duration(data);
+        let _ = winnow_iso8601::interval::parse_interval(data);
+
+        let _ = winnow_rfc3339::parse_date(data);
+        let _ = winnow_rfc3339::parse_time(data);
+        let _ = winnow_rfc3339::parse_datetime(data);
+
+        let _ = winnow_rfc9557::parse_date(data);
+        let _ = winnow_rfc9557::parse_time(data);
+        let _ = winnow_rfc9557::parse_datetime(data);
     }
 });